@@ -0,0 +1,69 @@
+use ndarray::{Array1, Array2};
+
+use crate::{error::Error, seq::RSeq};
+
+impl<T> RSeq<T>
+where
+    T: Clone + 'static,
+{
+    /// Collects the first `n` elements into an array.
+    pub fn take_array(&self, n: usize) -> Array1<T> {
+        Array1::from_iter(self.take_iter(n))
+    }
+
+    /// Collects the first `rows · cols` elements into a `rows × cols` matrix,
+    /// filling it one row at a time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rows · cols` overflows a `usize`.
+    pub fn take_into_array2(&self, rows: usize, cols: usize) -> Array2<T> {
+        let len = rows.checked_mul(cols).expect("matrix size overflows usize");
+        Array2::from_shape_vec((rows, cols), self.take_iter(len).collect())
+            .expect("prefix length matches the matrix shape")
+    }
+
+    /// The sequence that repeats the elements of `arr` in order forever.
+    ///
+    /// Returns [`Error::EmptyInput`] if `arr` is empty.
+    pub fn cycle_array(arr: Array1<T>) -> Result<Self, Error> {
+        Self::cycle(arr.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::{array, Array1};
+
+    use crate::{prelude::*, Error};
+
+    #[test]
+    fn take_array() {
+        let s = RSeq::incr(0).map(|n| n * n);
+        assert_eq!(s.take_array(6).to_vec(), s.take(6));
+        assert_eq!(s.take_array(0).len(), 0);
+        let lazy = RSeq::cons(1, RSeq::cons(2, RSeq::lazy(|| panic!("forced too far"))));
+        assert_eq!(lazy.take_array(2), array![1, 2]);
+    }
+
+    #[test]
+    fn take_into_array2() {
+        let m = RSeq::incr(0).take_into_array2(2, 3);
+        assert_eq!(m, array![[0, 1, 2], [3, 4, 5]]);
+        assert_eq!(m.shape(), &[2, 3]);
+        assert_eq!(m.strides(), &[3, 1]);
+        assert_eq!(RSeq::incr(0).take_into_array2(0, 4).shape(), &[0, 4]);
+    }
+
+    #[test]
+    fn cycle_array() {
+        let arr = array![1.0, 2.5, 4.0];
+        let s = RSeq::cycle_array(arr.clone()).unwrap();
+        assert_eq!(s.take(5), vec![1.0, 2.5, 4.0, 1.0, 2.5]);
+        assert_eq!(s.take_array(3), arr);
+        assert_eq!(
+            RSeq::<i32>::cycle_array(Array1::from_vec(vec![])).err(),
+            Some(Error::EmptyInput)
+        );
+    }
+}
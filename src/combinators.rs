@@ -0,0 +1,775 @@
+use alloc::{rc::Rc, vec::Vec};
+use core::cell::RefCell;
+
+use crate::{error::Error, seq::RSeq};
+
+impl<T> RSeq<T>
+where
+    T: Clone + 'static,
+{
+    /// Applies `f` to every element.
+    pub fn map<M>(&self, f: impl Fn(T) -> M + 'static) -> RSeq<M>
+    where
+        M: Clone + 'static,
+    {
+        self.map_rc(Rc::new(f))
+    }
+
+    fn map_rc<M>(&self, f: Rc<dyn Fn(T) -> M>) -> RSeq<M>
+    where
+        M: Clone + 'static,
+    {
+        let seq = self.clone();
+        RSeq::lazy(move || (f(seq.head().clone()), seq.tail().map_rc(f)))
+    }
+
+    /// Applies a fallible `f` to every element, keeping each outcome as an
+    /// element. Collect the successes with [`RSeq::take_ok`].
+    pub fn try_map<U, E>(&self, f: impl Fn(T) -> Result<U, E> + 'static) -> RSeq<Result<U, E>>
+    where
+        U: Clone + 'static,
+        E: Clone + 'static,
+    {
+        self.map(f)
+    }
+
+    /// Passes each element to `f` as it is computed and yields it unchanged.
+    /// Because elements are cached, `f` sees each position at most once.
+    pub fn inspect(&self, f: impl Fn(&T) + 'static) -> Self {
+        self.map(move |x| {
+            f(&x);
+            x
+        })
+    }
+
+    /// Applies a stateful `f` to every element.
+    ///
+    /// `f` is called once per element, in the order the elements are first
+    /// computed, so its state evolves with forcing order. Because elements are
+    /// cached, re-reading them never calls `f` again.
+    pub fn map_mut<M>(&self, f: impl FnMut(T) -> M + 'static) -> RSeq<M>
+    where
+        M: Clone + 'static,
+    {
+        self.map_shared(Rc::new(RefCell::new(f)))
+    }
+
+    fn map_shared<M, F>(&self, f: Rc<RefCell<F>>) -> RSeq<M>
+    where
+        M: Clone + 'static,
+        F: FnMut(T) -> M + 'static,
+    {
+        let seq = self.clone();
+        RSeq::lazy(move || {
+            let out = (f.borrow_mut())(seq.head().clone());
+            (out, seq.tail().map_shared(f))
+        })
+    }
+
+    /// Keeps only the elements satisfying `f`. The search for each match
+    /// runs when that element is first demanded.
+    ///
+    /// Loops forever if no further element satisfies `f`.
+    pub fn filter(&self, f: impl Fn(T) -> bool + 'static) -> Self {
+        self.filter_rc(Rc::new(f))
+    }
+
+    fn filter_rc(&self, f: Rc<dyn Fn(T) -> bool>) -> Self {
+        let seq = self.clone();
+        Self::lazy(move || {
+            let mut curr = seq;
+            loop {
+                let next = curr.tail();
+                if f(curr.head().clone()) {
+                    return (curr.head().clone(), next.filter_rc(f));
+                }
+                curr = next;
+            }
+        })
+    }
+
+    /// Applies `f` to every element, keeping only the `Some` results. The
+    /// search for each `Some` runs when that element is first demanded.
+    ///
+    /// Loops forever if `f` returns `None` for every further element.
+    pub fn filter_map<U>(&self, f: impl Fn(T) -> Option<U> + 'static) -> RSeq<U>
+    where
+        U: Clone + 'static,
+    {
+        self.filter_map_rc(Rc::new(f))
+    }
+
+    fn filter_map_rc<U>(&self, f: Rc<dyn Fn(T) -> Option<U>>) -> RSeq<U>
+    where
+        U: Clone + 'static,
+    {
+        let seq = self.clone();
+        RSeq::lazy(move || {
+            let mut curr = seq;
+            loop {
+                let next = curr.tail();
+                if let Some(out) = f(curr.head().clone()) {
+                    return (out, next.filter_map_rc(f));
+                }
+                curr = next;
+            }
+        })
+    }
+
+    /// Keeps only the elements whose corresponding element of `mask` is
+    /// `true`; both sequences advance together. The search for each kept
+    /// element runs when it is first demanded.
+    ///
+    /// Loops forever if no further element of `mask` is `true`.
+    pub fn compress(&self, mask: &RSeq<bool>) -> Self {
+        let mut seq = self.clone();
+        let mut mask = mask.clone();
+        Self::lazy(move || loop {
+            let (next, next_mask) = (seq.tail(), mask.tail());
+            if *mask.head() {
+                return (seq.head().clone(), next.compress(&next_mask));
+            }
+            seq = next;
+            mask = next_mask;
+        })
+    }
+
+    /// Threads a state through the sequence, emitting one value per element.
+    ///
+    /// `f` takes the current state and element and returns the next state
+    /// together with the value to emit.
+    pub fn scan<S, U>(&self, init: S, f: impl Fn(S, T) -> (S, U) + 'static) -> RSeq<U>
+    where
+        S: Clone + 'static,
+        U: Clone + 'static,
+    {
+        self.scan_rc(init, Rc::new(f))
+    }
+
+    fn scan_rc<S, U>(&self, init: S, f: Rc<dyn Fn(S, T) -> (S, U)>) -> RSeq<U>
+    where
+        S: Clone + 'static,
+        U: Clone + 'static,
+    {
+        let seq = self.clone();
+        RSeq::lazy(move || {
+            let (state, out) = f(init, seq.head().clone());
+            (out, seq.tail().scan_rc(state, f))
+        })
+    }
+
+    /// Pairs every element with its 0-based position.
+    pub fn enumerate(&self) -> RSeq<(usize, T)> {
+        self.scan(0, |i, x| (i + 1, (i, x)))
+    }
+
+    /// Overlapping windows of `N` consecutive elements, each one shifted a
+    /// single element along from the last. `N` must be non-zero.
+    pub fn windows<const N: usize>(&self) -> RSeq<[T; N]> {
+        const { assert!(N > 0, "window size must be non-zero") };
+        let seq = self.clone();
+        RSeq::lazy(move || {
+            let mut curr = seq;
+            let window = core::array::from_fn(|_| {
+                let head = curr.head().clone();
+                curr = curr.tail();
+                head
+            });
+            (window.clone(), curr.windows_after(window))
+        })
+    }
+
+    fn windows_after<const N: usize>(&self, prev: [T; N]) -> RSeq<[T; N]> {
+        let seq = self.clone();
+        RSeq::lazy(move || {
+            let mut window = prev;
+            window.rotate_left(1);
+            window[N - 1] = seq.head().clone();
+            (window.clone(), seq.tail().windows_after(window))
+        })
+    }
+
+    /// Consecutive non-overlapping groups of `n` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn chunks(&self, n: usize) -> RSeq<Vec<T>> {
+        assert!(n > 0, "chunk size must be non-zero");
+        let seq = self.clone();
+        RSeq::lazy(move || {
+            let mut curr = seq;
+            let mut chunk = Vec::with_capacity(n);
+            for _ in 0..n {
+                chunk.push(curr.head().clone());
+                curr = curr.tail();
+            }
+            (chunk, curr.chunks(n))
+        })
+    }
+
+    /// Replaces every element with the elements of `f(element)`, in order.
+    /// Elements whose expansion is empty contribute nothing.
+    ///
+    /// Loops forever if every further expansion is empty.
+    pub fn flat_map<U>(&self, f: impl Fn(T) -> Vec<U> + 'static) -> RSeq<U>
+    where
+        U: Clone + 'static,
+    {
+        self.flat_map_rc(Rc::new(f))
+    }
+
+    fn flat_map_rc<U>(&self, f: Rc<dyn Fn(T) -> Vec<U>>) -> RSeq<U>
+    where
+        U: Clone + 'static,
+    {
+        let seq = self.clone();
+        RSeq::defer(move || {
+            let mut curr = seq;
+            loop {
+                let batch = f(curr.head().clone());
+                curr = curr.tail();
+                if !batch.is_empty() {
+                    return curr.flat_map_batch(batch.into(), 0, f);
+                }
+            }
+        })
+    }
+
+    fn flat_map_batch<U>(&self, batch: Rc<[U]>, i: usize, f: Rc<dyn Fn(T) -> Vec<U>>) -> RSeq<U>
+    where
+        U: Clone + 'static,
+    {
+        if i == batch.len() {
+            return self.flat_map_rc(f);
+        }
+        let seq = self.clone();
+        RSeq::lazy(move || (batch[i].clone(), seq.flat_map_batch(batch, i + 1, f)))
+    }
+
+    /// Places `sep` between every pair of consecutive elements, so elements sit
+    /// at even positions and separators at odd ones.
+    pub fn intersperse(&self, sep: T) -> Self {
+        let seq = self.clone();
+        Self::lazy(move || {
+            let rest = seq.tail();
+            (
+                seq.head().clone(),
+                Self::lazy(move || (sep.clone(), rest.intersperse(sep))),
+            )
+        })
+    }
+
+    /// Collapses each run of consecutive elements that `eq` considers equal
+    /// into its first element. The scan past a run happens when the next
+    /// element is first demanded.
+    ///
+    /// Loops forever if some run never ends.
+    pub fn dedup_by(&self, eq: impl Fn(&T, &T) -> bool + 'static) -> Self {
+        self.dedup_by_rc(Rc::new(eq))
+    }
+
+    fn dedup_by_rc<F>(&self, eq: Rc<F>) -> Self
+    where
+        F: Fn(&T, &T) -> bool + 'static,
+    {
+        let seq = self.clone();
+        Self::lazy(move || {
+            let head = seq.head().clone();
+            let first = head.clone();
+            let same = Rc::clone(&eq);
+            let rest = seq.tail().skip_while(move |x| same(&first, x));
+            (head, rest.dedup_by_rc(eq))
+        })
+    }
+
+    /// Every `step`-th element, starting with the head.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    pub fn step_by(&self, step: usize) -> Self {
+        self.step_by_from(0, step)
+    }
+
+    /// Every `step`-th element, starting with element `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    pub fn step_by_from(&self, offset: usize, step: usize) -> Self {
+        assert!(step > 0, "step must be non-zero");
+        let seq = self.clone();
+        Self::lazy(move || {
+            let start = seq.skip(offset);
+            (
+                start.head().clone(),
+                start.tail().step_by_from(step - 1, step),
+            )
+        })
+    }
+
+    /// Alternates elements from `left` and `right`, starting with `left`.
+    pub fn interleave(left: &Self, right: &Self) -> Self {
+        let left = left.clone();
+        let right = right.clone();
+        Self::lazy(move || (left.head().clone(), Self::interleave(&right, &left.tail())))
+    }
+
+    /// Takes one element from each of `seqs` in turn, cycling back to the
+    /// first after the last.
+    ///
+    /// Returns [`Error::EmptyInput`] if `seqs` is empty.
+    pub fn interleave_all(seqs: Vec<Self>) -> Result<Self, Error> {
+        if seqs.is_empty() {
+            return Err(Error::EmptyInput);
+        }
+        Ok(Self::round_robin(seqs, 0))
+    }
+
+    fn round_robin(mut seqs: Vec<Self>, i: usize) -> Self {
+        Self::lazy(move || {
+            let head = seqs[i].head().clone();
+            seqs[i] = seqs[i].tail();
+            let next = (i + 1) % seqs.len();
+            (head, Self::round_robin(seqs, next))
+        })
+    }
+
+    /// Pairs up corresponding elements of `self` and `other`.
+    pub fn zip<U>(&self, other: &RSeq<U>) -> RSeq<(T, U)>
+    where
+        U: Clone + 'static,
+    {
+        self.zip_with(other, |a, b| (a, b))
+    }
+
+    /// Combines corresponding elements of `self` and `other` with `f`.
+    pub fn zip_with<U, V>(&self, other: &RSeq<U>, f: impl Fn(T, U) -> V + 'static) -> RSeq<V>
+    where
+        U: Clone + 'static,
+        V: Clone + 'static,
+    {
+        self.zip_with_rc(other, Rc::new(f))
+    }
+
+    fn zip_with_rc<U, V>(&self, other: &RSeq<U>, f: Rc<dyn Fn(T, U) -> V>) -> RSeq<V>
+    where
+        U: Clone + 'static,
+        V: Clone + 'static,
+    {
+        let left = self.clone();
+        let right = other.clone();
+        RSeq::lazy(move || {
+            (
+                f(left.head().clone(), right.head().clone()),
+                left.tail().zip_with_rc(&right.tail(), f),
+            )
+        })
+    }
+
+    /// Gathers the corresponding elements of every sequence in `seqs`:
+    /// element `i` holds element `i` of each input, in order.
+    ///
+    /// Returns [`Error::EmptyInput`] if `seqs` is empty.
+    pub fn zip_n(seqs: Vec<Self>) -> Result<RSeq<Vec<T>>, Error> {
+        Self::map_n(seqs, <[T]>::to_vec)
+    }
+
+    /// Combines the corresponding elements of every sequence in `seqs` with
+    /// `f`. The rows passed to `f` share one buffer.
+    ///
+    /// Returns [`Error::EmptyInput`] if `seqs` is empty.
+    pub fn map_n<U>(seqs: Vec<Self>, f: impl Fn(&[T]) -> U + 'static) -> Result<RSeq<U>, Error>
+    where
+        U: Clone + 'static,
+    {
+        if seqs.is_empty() {
+            return Err(Error::EmptyInput);
+        }
+        let row = Vec::with_capacity(seqs.len());
+        Ok(Self::rows_from(seqs, row, Rc::new(f)))
+    }
+
+    fn rows_from<U, F>(mut seqs: Vec<Self>, mut row: Vec<T>, f: Rc<F>) -> RSeq<U>
+    where
+        U: Clone + 'static,
+        F: Fn(&[T]) -> U + 'static,
+    {
+        RSeq::lazy(move || {
+            row.clear();
+            for seq in &mut seqs {
+                row.push(seq.head().clone());
+                *seq = seq.tail();
+            }
+            (f(&row), Self::rows_from(seqs, row, f))
+        })
+    }
+}
+
+impl<T> RSeq<T>
+where
+    T: PartialEq + Clone + 'static,
+{
+    /// Collapses each run of consecutive equal elements into one.
+    ///
+    /// Loops forever if some run never ends.
+    pub fn dedup(&self) -> Self {
+        self.dedup_by(|a, b| a == b)
+    }
+}
+
+impl<U, E> RSeq<Result<U, E>>
+where
+    U: Clone + 'static,
+    E: Clone + 'static,
+{
+    /// Collects the first `n` elements, which must all be `Ok`. Stops at the
+    /// first `Err` and returns it, forcing nothing after it.
+    pub fn take_ok(&self, n: usize) -> Result<Vec<U>, E> {
+        self.take_iter(n).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    };
+
+    use crate::{prelude::*, Error};
+
+    #[test]
+    fn map() {
+        let s = RSeq::incr(2);
+        assert_eq!(s.map(|n| n * 2).take(5), vec![4, 6, 8, 10, 12]);
+    }
+
+    #[test]
+    fn try_map() {
+        let bytes = RSeq::incr(250i32).try_map(u8::try_from);
+        assert_eq!(bytes.take_ok(6), Ok(vec![250, 251, 252, 253, 254, 255]));
+        assert!(bytes.take_ok(10).is_err());
+        assert!(bytes.nth(6).is_err());
+        assert_eq!(bytes.nth(5), Ok(255));
+        let parsed = RSeq::cons(
+            "1",
+            RSeq::cons("x", RSeq::lazy(|| panic!("forced past error"))),
+        )
+        .try_map(str::parse::<i32>);
+        assert!(parsed.take_ok(5).is_err());
+        assert_eq!(parsed.take_ok(1), Ok(vec![1]));
+        assert_eq!(parsed.take_ok(0), Ok(vec![]));
+    }
+
+    #[test]
+    fn inspect() {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let s = {
+            let seen = Rc::clone(&seen);
+            RSeq::incr(1)
+                .map(|n| n * 10)
+                .inspect(move |&n| seen.borrow_mut().push(n))
+        };
+        assert!(seen.borrow().is_empty());
+        assert_eq!(s.take(3), vec![10, 20, 30]);
+        assert_eq!(*seen.borrow(), vec![10, 20, 30]);
+        assert_eq!(s.take(2), vec![10, 20]);
+        assert_eq!(s.nth(4), 50);
+        assert_eq!(*seen.borrow(), vec![10, 20, 30, 40, 50]);
+    }
+
+    #[test]
+    fn map_capturing_vec() {
+        let multipliers: Vec<i64> = vec![2, 3, 5];
+        let s = RSeq::incr(1i64)
+            .enumerate()
+            .map(move |(i, n)| n * multipliers[i % multipliers.len()]);
+        assert_eq!(s.take(6), vec![2, 6, 15, 8, 15, 30]);
+        let banned: Vec<i32> = (3..5).collect();
+        let kept = RSeq::incr(1).filter(move |n| !banned.contains(&n));
+        assert_eq!(kept.take(4), vec![1, 2, 5, 6]);
+    }
+
+    #[test]
+    fn map_vecs() {
+        let s = RSeq::incr(0).chunks(2).map(|mut v: Vec<i32>| {
+            v.reverse();
+            v
+        });
+        assert_eq!(s.take(2), vec![vec![1, 0], vec![3, 2]]);
+    }
+
+    #[test]
+    fn map_mut() {
+        let mut count = 0;
+        let s = RSeq::incr(10).map_mut(move |n| {
+            count += 1;
+            (count - 1, n)
+        });
+        assert_eq!(
+            s.iter().take(3).collect::<Vec<_>>(),
+            vec![(0, 10), (1, 11), (2, 12)]
+        );
+        assert_eq!(s.take(4), vec![(0, 10), (1, 11), (2, 12), (3, 13)]);
+    }
+
+    #[test]
+    fn filter() {
+        let s = RSeq::incr(2);
+        assert_eq!(s.filter(|n| n % 2 == 0).take(5), vec![2, 4, 6, 8, 10]);
+        assert_eq!(s.filter(|n| n % 2 != 0).take(5), vec![3, 5, 7, 9, 11]);
+    }
+
+    #[test]
+    fn filter_long_gaps() {
+        let s = RSeq::incr(0u64).filter(|n| n % 3_000_000 == 0);
+        assert_eq!(s.take(3), vec![0, 3_000_000, 6_000_000]);
+    }
+
+    #[test]
+    fn lazy_construction() {
+        thread_local! {
+            static CALLS: Cell<usize> = const { Cell::new(0) };
+        }
+        let expensive = RSeq::from_fn(|n| {
+            CALLS.with(|c| c.set(c.get() + 1));
+            n
+        });
+        let s = expensive.map(|n| n * 2).filter(|n| n % 3 == 0);
+        let t = RSeq::interleave(&s, &(&s + &s));
+        assert_eq!(CALLS.with(Cell::get), 0);
+        assert_eq!(t.take(4), vec![0, 0, 6, 12]);
+        assert_eq!(CALLS.with(Cell::get), 4);
+        let _never = RSeq::incr(0).filter(|_| false);
+    }
+
+    #[test]
+    fn filter_map() {
+        let s = RSeq::incr(0).filter_map(|n| {
+            let square = n * n;
+            (square % 2 == 0).then_some(square)
+        });
+        assert_eq!(s.take(4), vec![0, 4, 16, 36]);
+        let parsed = RSeq::cycle(vec!["1", "x", "2", "", "3"])
+            .unwrap()
+            .filter_map(|t| t.parse::<i32>().ok());
+        assert_eq!(parsed.take(4), vec![1, 2, 3, 1]);
+    }
+
+    #[test]
+    fn scan() {
+        let s = RSeq::incr(1).scan(0, |acc, n| (acc + n, acc + n));
+        assert_eq!(s.take(5), vec![1, 3, 6, 10, 15]);
+    }
+
+    #[test]
+    fn scan_tuple_state() {
+        let s = RSeq::incr(8).scan((String::new(), 0), |(mut digits, count), n| {
+            digits.push_str(&n.to_string());
+            let len = digits.len();
+            ((digits, count + 1), (count, len))
+        });
+        assert_eq!(s.take(4), vec![(0, 1), (1, 2), (2, 4), (3, 6)]);
+    }
+
+    #[test]
+    fn enumerate() {
+        let s = RSeq::incr(10).enumerate();
+        assert_eq!(s.take(3), vec![(0, 10), (1, 11), (2, 12)]);
+        let odd = s.filter(|(_, n)| n % 2 != 0);
+        assert_eq!(odd.take(3), vec![(1, 11), (3, 13), (5, 15)]);
+    }
+
+    #[test]
+    fn windows() {
+        let s = RSeq::incr(0);
+        assert_eq!(s.windows::<1>().take(3), vec![[0], [1], [2]]);
+        assert_eq!(s.windows::<2>().take(3), vec![[0, 1], [1, 2], [2, 3]]);
+        assert_eq!(
+            s.windows::<3>().take(3),
+            vec![[0, 1, 2], [1, 2, 3], [2, 3, 4]]
+        );
+    }
+
+    #[test]
+    fn chunks() {
+        let s = RSeq::incr(0).chunks(3);
+        assert_eq!(s.take(2), vec![vec![0, 1, 2], vec![3, 4, 5]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn chunks_zero() {
+        RSeq::incr(0).chunks(0);
+    }
+
+    #[test]
+    fn flat_map() {
+        let s = RSeq::incr(1).flat_map(|n| vec![n; n as usize]);
+        assert_eq!(s.take(6), vec![1, 2, 2, 3, 3, 3]);
+        let t = RSeq::incr(0).flat_map(|n| if n % 2 == 0 { vec![] } else { vec![n, -n] });
+        assert_eq!(t.take(6), vec![1, -1, 3, -3, 5, -5]);
+    }
+
+    #[test]
+    fn capturing_closures() {
+        let names = Vec::from(["zero", "one", "two"]);
+        let words = RSeq::incr(0).flat_map(move |n: usize| vec![names[n % 3]; n % 2 + 1]);
+        assert_eq!(words.take(4), vec!["zero", "one", "one", "two"]);
+        let sep = String::from(",");
+        let joined = words.scan(String::new(), move |acc, w| {
+            let next = acc + &sep + w;
+            (next.clone(), next)
+        });
+        assert_eq!(joined.nth(2), ",zero,one,one");
+        let suffix = String::from("!");
+        let shouted = words.zip_with(&RSeq::incr(0), move |w, n| format!("{w}{n}{suffix}"));
+        assert_eq!(shouted.take(2), vec!["zero0!", "one1!"]);
+        let vowels = String::from("aeiou");
+        let starts_with_vowel = move |w: &str| w.starts_with(|c| vowels.contains(c));
+        let runs = words.dedup_by(move |a, b| starts_with_vowel(a) == starts_with_vowel(b));
+        assert_eq!(runs.take(3), vec!["zero", "one", "two"]);
+    }
+
+    #[test]
+    fn intersperse() {
+        let s = RSeq::incr(1).intersperse(0);
+        assert_eq!(s.take(5), vec![1, 0, 2, 0, 3]);
+        assert_eq!(s.take(4), vec![1, 0, 2, 0]);
+    }
+
+    #[test]
+    fn dedup() {
+        let s = RSeq::cycle(vec![1, 1, 2, 2, 2, 3]).unwrap();
+        assert_eq!(s.dedup().take(6), vec![1, 2, 3, 1, 2, 3]);
+        assert_eq!(*RSeq::cnst(7).dedup().head(), 7);
+        let close = RSeq::cycle(vec![1.0f64, 1.05, 2.0, 2.01, 1.0]).unwrap();
+        assert_eq!(
+            close.dedup_by(|a, b| (a - b).abs() < 0.1).take(3),
+            vec![1.0, 2.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn step_by() {
+        let s = RSeq::incr(0);
+        assert_eq!(s.step_by(1).take(4), vec![0, 1, 2, 3]);
+        assert_eq!(s.step_by(3).take(4), vec![0, 3, 6, 9]);
+        assert_eq!(s.step_by_from(1, 3).take(4), vec![1, 4, 7, 10]);
+        assert_eq!(s.step_by_from(2, 5).take(3), vec![2, 7, 12]);
+    }
+
+    #[test]
+    fn step_by_lazy() {
+        let (s, counter) = RSeq::incr(0).counted();
+        let every_fourth = s.step_by_from(1, 4);
+        assert_eq!(*every_fourth.head(), 1);
+        assert_eq!(counter.count(), 2);
+        assert_eq!(every_fourth.nth(1), 5);
+        assert_eq!(counter.count(), 6);
+        let rest = every_fourth.tail().tail();
+        assert_eq!(counter.count(), 6);
+        assert_eq!(*rest.head(), 9);
+        assert_eq!(counter.count(), 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "step must be non-zero")]
+    fn step_by_zero() {
+        RSeq::incr(0).step_by(0);
+    }
+
+    #[test]
+    fn interleave() {
+        let s = RSeq::incr(0).filter(|n| n % 2 == 0);
+        let t = RSeq::incr(0).filter(|n| n % 2 != 0);
+        assert_eq!(RSeq::interleave(&s, &t).take(5), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn interleave_all() {
+        let consts = (0..3).map(RSeq::cnst).collect();
+        assert_eq!(
+            RSeq::interleave_all(consts).unwrap().take(7),
+            vec![0, 1, 2, 0, 1, 2, 0]
+        );
+        let residues = (0..3)
+            .map(|r| RSeq::incr(0).filter(move |n| n % 3 == r))
+            .collect();
+        let s = RSeq::interleave_all(residues).unwrap();
+        assert_eq!(s.take(20), RSeq::incr(0).take(20));
+        let single = RSeq::interleave_all(vec![RSeq::incr(5)]).unwrap();
+        assert_eq!(single.take(4), vec![5, 6, 7, 8]);
+        assert_eq!(
+            RSeq::<i32>::interleave_all(vec![]).err(),
+            Some(Error::EmptyInput)
+        );
+    }
+
+    #[test]
+    fn zip() {
+        let s = RSeq::incr(0);
+        assert_eq!(
+            s.zip(&RSeq::cnst('a')).take(5),
+            vec![(0, 'a'), (1, 'a'), (2, 'a'), (3, 'a'), (4, 'a')]
+        );
+        let odds = RSeq::incr(0).filter(|n| n % 2 != 0);
+        assert_eq!(
+            s.zip(&odds).take(5),
+            vec![(0, 1), (1, 3), (2, 5), (3, 7), (4, 9)]
+        );
+    }
+
+    #[test]
+    fn compress() {
+        let s = RSeq::incr(0);
+        let alternate = RSeq::cycle(vec![true, false]).unwrap();
+        assert_eq!(s.compress(&alternate).take(5), vec![0, 2, 4, 6, 8]);
+        let squares = &s * &s;
+        let small = squares.lt(&RSeq::cnst(50)).prepend(vec![false]);
+        assert_eq!(s.compress(&small).take(3), vec![1, 2, 3]);
+        let sparse = s.rem_scalar(1_000_000).eq_seq(&RSeq::cnst(0));
+        assert_eq!(s.compress(&sparse).take(3), vec![0, 1_000_000, 2_000_000]);
+    }
+
+    #[test]
+    fn zip_n() {
+        let seqs = vec![
+            RSeq::cnst(7),
+            RSeq::incr(0),
+            RSeq::incr(0).filter(|n| n % 3 == 0),
+        ];
+        let rows = RSeq::zip_n(seqs.clone()).unwrap();
+        assert_eq!(
+            rows.take(3),
+            vec![vec![7, 0, 0], vec![7, 1, 3], vec![7, 2, 6]]
+        );
+        assert!(rows.take(20).iter().all(|row| row.len() == 3));
+        let sums = RSeq::map_n(seqs, |row| row.iter().sum::<i32>()).unwrap();
+        assert_eq!(sums.take(4), vec![7, 11, 15, 19]);
+        assert_eq!(RSeq::<i32>::zip_n(vec![]).err(), Some(Error::EmptyInput));
+    }
+
+    #[test]
+    fn zip_with() {
+        let s = RSeq::incr(1);
+        let t = RSeq::cnst(0.5);
+        assert_eq!(
+            s.zip_with(&t, |n, x| n as f64 * x).take(4),
+            vec![0.5, 1.0, 1.5, 2.0]
+        );
+    }
+
+    #[test]
+    fn zip_with_fibonacci() {
+        let fibs = RSeq::fix(|fibs| {
+            let fibs = fibs.clone();
+            let rest = RSeq::defer(move || fibs.zip_with(&fibs.tail(), |a, b| a + b));
+            RSeq::cons(0u64, RSeq::cons(1, rest))
+        });
+        assert_eq!(fibs.take(10), vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+        assert_eq!(fibs.nth(90), 2_880_067_194_370_816_120);
+    }
+}
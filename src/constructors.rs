@@ -0,0 +1,348 @@
+use alloc::{collections::BTreeMap, rc::Rc, vec, vec::Vec};
+use core::{
+    cell::OnceCell,
+    ops::{Add, Mul},
+};
+
+use num_traits::Num;
+
+use crate::{error::Error, seq::RSeq, step::Step};
+
+impl<T> RSeq<T>
+where
+    T: Step + Clone + 'static,
+{
+    /// The sequence `start, start + 1, start + 2, ...`, or in general `start`
+    /// followed by its successive [`Step::next`] values.
+    pub fn incr(start: T) -> Self {
+        Self::lazy(move || {
+            let next = start.next();
+            (start, Self::incr(next))
+        })
+    }
+}
+
+impl<T> RSeq<T>
+where
+    T: Num + Clone + 'static,
+{
+    /// The sequence `start, start + 1, start + 2, ...` for any numeric type,
+    /// including those without a [`Step`] impl such as big integers and
+    /// ratios.
+    pub fn incr_num(start: T) -> Self {
+        Self::arithmetic(start, T::one())
+    }
+}
+
+impl<T> RSeq<T>
+where
+    T: Add<Output = T> + Clone + 'static,
+{
+    /// The sequence `start, start + step, start + 2·step, ...`.
+    pub fn arithmetic(start: T, step: T) -> Self {
+        Self::lazy(move || {
+            let next = start.clone() + step.clone();
+            (start, Self::arithmetic(next, step))
+        })
+    }
+}
+
+impl<T> RSeq<T>
+where
+    T: Clone + 'static,
+{
+    /// The sequence that repeats `v` forever.
+    pub fn cnst(v: T) -> Self {
+        Self::lazy(move || (v.clone(), Self::cnst(v)))
+    }
+
+    /// The sequence `start, f(start), f(f(start)), ...`.
+    pub fn unfold(start: T, f: impl Fn(T) -> T + 'static) -> Self {
+        Self::unfold_rc(start, Rc::new(f))
+    }
+
+    fn unfold_rc(start: T, f: Rc<dyn Fn(T) -> T>) -> Self {
+        Self::lazy(move || {
+            let next = f(start.clone());
+            (start, Self::unfold_rc(next, f))
+        })
+    }
+
+    /// The sequence generated from a hidden state: `f` maps each state to the
+    /// element to emit and the next state.
+    pub fn unfold_map<S>(state: S, f: impl Fn(S) -> (T, S) + 'static) -> Self
+    where
+        S: Clone + 'static,
+    {
+        Self::unfold_map_rc(state, Rc::new(f))
+    }
+
+    fn unfold_map_rc<S>(state: S, f: Rc<dyn Fn(S) -> (T, S)>) -> Self
+    where
+        S: Clone + 'static,
+    {
+        Self::lazy(move || {
+            let (out, next) = f(state);
+            (out, Self::unfold_map_rc(next, f))
+        })
+    }
+
+    /// The sequence whose element `n` is `f(n)`.
+    pub fn from_fn(f: impl Fn(usize) -> T + 'static) -> Self {
+        Self::from_fn_at(0, Rc::new(f))
+    }
+
+    fn from_fn_at(n: usize, f: Rc<dyn Fn(usize) -> T>) -> Self {
+        Self::lazy(move || (f(n), Self::from_fn_at(n + 1, f)))
+    }
+
+    /// The sequence defined by `f` in terms of itself: the result `s`
+    /// satisfies `s = f(&s)`.
+    ///
+    /// `f` must not demand any element of its argument while building its
+    /// result; wrap such uses in [`RSeq::defer`]. Each element of the result
+    /// may depend only on earlier ones. Because the result refers to itself,
+    /// its cached elements are never freed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `f` demands an element of its argument before returning.
+    pub fn fix(f: impl FnOnce(&Self) -> Self) -> Self {
+        let (knot, this) = Self::knot();
+        let seq = f(&this);
+        let _ = knot.set(seq.clone());
+        seq
+    }
+
+    /// The pair of sequences defined by `f` in terms of each other: the
+    /// results `(a, b)` satisfy `(a, b) = f(&a, &b)`.
+    ///
+    /// As with [`RSeq::fix`], `f` must not demand any element of either
+    /// argument while building its results. Each element of either result
+    /// may depend only on strictly earlier elements of the two sequences, so
+    /// that demanding any element always makes progress.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `f` demands an element of either argument before returning.
+    pub fn fix2(f: impl FnOnce(&Self, &Self) -> (Self, Self)) -> (Self, Self) {
+        let (first_knot, first) = Self::knot();
+        let (second_knot, second) = Self::knot();
+        let (a, b) = f(&first, &second);
+        let _ = first_knot.set(a.clone());
+        let _ = second_knot.set(b.clone());
+        (a, b)
+    }
+
+    /// A cell to tie a recursive definition into, together with a sequence
+    /// that behaves like the cell's contents once it is filled.
+    fn knot() -> (Rc<OnceCell<Self>>, Self) {
+        let knot = Rc::new(OnceCell::<Self>::new());
+        let seq = {
+            let knot = Rc::clone(&knot);
+            Self::defer(move || {
+                knot.get()
+                    .expect("fix's argument was demanded before its definition returned")
+                    .clone()
+            })
+        };
+        (knot, seq)
+    }
+
+    /// The sequence that repeats `items` in order forever.
+    ///
+    /// Returns [`Error::EmptyInput`] if `items` is empty.
+    pub fn cycle(items: Vec<T>) -> Result<Self, Error> {
+        if items.is_empty() {
+            return Err(Error::EmptyInput);
+        }
+        Ok(Self::cycle_from(items.into(), 0))
+    }
+
+    fn cycle_from(items: Rc<[T]>, i: usize) -> Self {
+        Self::lazy(move || {
+            let next = (i + 1) % items.len();
+            (items[i].clone(), Self::cycle_from(items, next))
+        })
+    }
+}
+
+impl<T> RSeq<T>
+where
+    T: Mul<Output = T> + Clone + 'static,
+{
+    /// The sequence `start, start·ratio, start·ratio², ...`.
+    pub fn geometric(start: T, ratio: T) -> Self {
+        Self::unfold(start, move |x| x * ratio.clone())
+    }
+}
+
+impl RSeq<u64> {
+    /// The prime numbers `2, 3, 5, 7, ...`, found with an incremental sieve.
+    pub fn primes() -> Self {
+        Self::sieve_from(2, BTreeMap::new())
+    }
+
+    /// Continues the sieve at `n`. `composites` maps each upcoming composite
+    /// to the primes that were found to divide it.
+    fn sieve_from(n: u64, mut composites: BTreeMap<u64, Vec<u64>>) -> Self {
+        Self::lazy(move || {
+            let mut n = n;
+            while let Some(factors) = composites.remove(&n) {
+                for p in factors {
+                    composites.entry(n + p).or_default().push(p);
+                }
+                n += 1;
+            }
+            composites.insert(n * n, vec![n]);
+            (n, Self::sieve_from(n + 1, composites))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, Error};
+
+    #[test]
+    fn cnst() {
+        let s = RSeq::cnst(1);
+        assert_eq!(s.take(5), vec![1, 1, 1, 1, 1]);
+    }
+
+    #[test]
+    fn incr() {
+        let s = RSeq::incr(2);
+        assert_eq!(s.take(5), vec![2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn incr_num() {
+        assert_eq!(RSeq::incr_num(2).take(3), vec![2, 3, 4]);
+        assert!(RSeq::incr_num(-1.5).eq_prefix(&RSeq::incr(-1.5), 10));
+    }
+
+    #[test]
+    fn cycle() {
+        let s = RSeq::cycle(vec![1, 2, 3]).unwrap();
+        assert_eq!(s.take(7), vec![1, 2, 3, 1, 2, 3, 1]);
+        assert_eq!(s.filter(|n| n != 2).take(4), vec![1, 3, 1, 3]);
+        assert_eq!((&s * &s).take(4), vec![1, 4, 9, 1]);
+        let single = RSeq::cycle(vec![4]).unwrap();
+        assert_eq!(single.take(5), RSeq::cnst(4).take(5));
+        assert_eq!(RSeq::<i32>::cycle(vec![]).err(), Some(Error::EmptyInput));
+    }
+
+    #[test]
+    fn unfold_map() {
+        let fibs = RSeq::unfold_map((0, 1), |(a, b)| (a, (b, a + b)));
+        assert_eq!(fibs.take(10), vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+        let tribs = RSeq::unfold_map((0i64, 0i64, 1i64), |(a, b, c)| (a, (b, c, a + b + c)));
+        assert_eq!(tribs.take(8), vec![0, 0, 1, 1, 2, 4, 7, 13]);
+        let digits = Vec::from([3, 1, 4]);
+        let walk = RSeq::unfold_map(0, move |i| (digits[i], (i + 1) % 3));
+        assert_eq!(walk.take(4), vec![3, 1, 4, 3]);
+    }
+
+    #[test]
+    fn from_fn() {
+        let s = RSeq::from_fn(|n| n * n);
+        assert_eq!(s.take(5), vec![0, 1, 4, 9, 16]);
+        assert_eq!(s.map(|n| n + 1).take(3), vec![1, 2, 5]);
+        let t = RSeq::from_fn(|n| n);
+        assert_eq!((&s - &t).take(4), vec![0, 0, 2, 6]);
+        let table = Vec::from([10, 20]);
+        let looked = RSeq::from_fn(move |n| table[n % 2]);
+        assert_eq!(looked.take(3), vec![10, 20, 10]);
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(RSeq::arithmetic(10, -3).take(4), vec![10, 7, 4, 1]);
+        assert_eq!(
+            RSeq::arithmetic(0.0, 0.25).take(5),
+            vec![0.0, 0.25, 0.5, 0.75, 1.0]
+        );
+    }
+
+    #[test]
+    fn geometric() {
+        assert_eq!(RSeq::geometric(1, 2).take(6), vec![1, 2, 4, 8, 16, 32]);
+        let sum: f64 = RSeq::geometric(1.0, 0.5).take(50).iter().sum();
+        assert!((sum - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn primes() {
+        let s = RSeq::primes();
+        assert_eq!(s.take(10), vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+        assert_eq!(s.nth(999), 7919);
+        assert_eq!(s.take(10_000)[9_999], 104_729);
+    }
+
+    #[test]
+    fn unfold_strings() {
+        let s = RSeq::unfold(String::from("a"), |t| t + "b");
+        assert_eq!(s.take(3), vec!["a", "ab", "abb"]);
+    }
+
+    #[test]
+    fn fix() {
+        let fibs = RSeq::fix(|fibs| {
+            let fibs = fibs.clone();
+            RSeq::cons(
+                0u64,
+                RSeq::cons(1, RSeq::defer(move || &fibs + &fibs.skip(1))),
+            )
+        });
+        let expected = RSeq::unfold((0, 1), |(x, y)| (y, x + y)).map(|(x, _)| x);
+        assert!(fibs.eq_prefix(&expected, 60));
+        assert_eq!(fibs.nth(50), 12_586_269_025);
+        let nats = RSeq::fix(|nats| RSeq::cons(0, nats + 1));
+        assert_eq!(nats.take(5), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "demanded before its definition returned")]
+    fn fix_eager() {
+        RSeq::fix(|s: &RSeq<i32>| s.tail());
+    }
+
+    #[test]
+    fn fix2() {
+        let (a, b) = RSeq::fix2(|a, b| (RSeq::cons(1, b + 1), RSeq::cons(1, a * 2)));
+        assert_eq!(a.take(6), vec![1, 2, 3, 5, 7, 11]);
+        assert_eq!(b.take(6), vec![1, 2, 4, 6, 10, 14]);
+
+        let (female, male) = RSeq::fix2(|f, m| {
+            let (f, m) = (f.clone(), m.clone());
+            let female = {
+                let (f, m) = (f.clone(), m.clone());
+                RSeq::incr(1usize).map(move |n| n - m.nth(f.nth(n - 1)))
+            };
+            let male = RSeq::incr(1usize).map(move |n| n - f.nth(m.nth(n - 1)));
+            (RSeq::cons(1, female), RSeq::cons(0, male))
+        });
+        // OEIS A005378 and A005379.
+        assert_eq!(
+            female.take(15),
+            vec![1, 1, 2, 2, 3, 3, 4, 5, 5, 6, 6, 7, 8, 8, 9]
+        );
+        assert_eq!(
+            male.take(15),
+            vec![0, 0, 1, 2, 2, 3, 4, 4, 5, 6, 6, 7, 7, 8, 9]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "demanded before its definition returned")]
+    fn fix2_eager() {
+        RSeq::fix2(|a: &RSeq<i32>, b| (b.tail(), a.clone()));
+    }
+
+    #[test]
+    fn unfold() {
+        let s = RSeq::unfold((0, 1), |(x, y)| (y, x + y)).map(|(x, _)| x);
+        assert_eq!(s.take(10), vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+    }
+}
@@ -0,0 +1,97 @@
+use alloc::rc::Rc;
+use core::cell::Cell;
+
+use crate::seq::RSeq;
+
+/// A shared count of the elements computed through a sequence returned by
+/// [`RSeq::counted`]. Clones read and reset the same count.
+#[derive(Debug, Clone, Default)]
+pub struct ForceCounter {
+    count: Rc<Cell<u64>>,
+}
+
+impl ForceCounter {
+    /// How many elements have been computed so far.
+    pub fn count(&self) -> u64 {
+        self.count.get()
+    }
+
+    /// Sets the count back to zero.
+    pub fn reset(&self) {
+        self.count.set(0);
+    }
+
+    fn record(&self) {
+        self.count.set(self.count.get() + 1);
+    }
+}
+
+impl<T> RSeq<T>
+where
+    T: Clone + 'static,
+{
+    /// Returns a sequence with the same elements, together with a counter
+    /// that goes up by one each time one of its elements is computed.
+    ///
+    /// Elements are cached, so the count is the number of distinct positions
+    /// demanded through the returned sequence, however many times and by
+    /// whichever combinators they are read.
+    pub fn counted(&self) -> (Self, ForceCounter) {
+        let counter = ForceCounter::default();
+        (self.counted_from(counter.clone()), counter)
+    }
+
+    fn counted_from(&self, counter: ForceCounter) -> Self {
+        let seq = self.clone();
+        Self::lazy(move || {
+            counter.record();
+            (seq.head().clone(), seq.tail().counted_from(counter))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn cnst() {
+        let (s, counter) = RSeq::cnst(7).counted();
+        assert_eq!(counter.count(), 0);
+        assert_eq!(s.take(5), vec![7; 5]);
+        assert_eq!(counter.count(), 5);
+        assert_eq!(s.take(5), vec![7; 5]);
+        assert_eq!(counter.count(), 5);
+        counter.reset();
+        assert_eq!(s.take(6).len(), 6);
+        assert_eq!(counter.count(), 1);
+    }
+
+    #[test]
+    fn filter() {
+        let (s, counter) = RSeq::incr(0).counted();
+        let multiples = s.filter(|n| n % 3 == 0);
+        assert_eq!(counter.count(), 0);
+        assert_eq!(multiples.take(10), vec![0, 3, 6, 9, 12, 15, 18, 21, 24, 27]);
+        assert_eq!(counter.count(), 28);
+    }
+
+    #[test]
+    fn shared_reads() {
+        let (s, counter) = RSeq::incr(1).counted();
+        let sums = &s + &s.skip(1);
+        assert_eq!(sums.take(10), vec![3, 5, 7, 9, 11, 13, 15, 17, 19, 21]);
+        assert_eq!(counter.count(), 11);
+
+        let (fibs, counter) = RSeq::fix(|fibs| {
+            let fibs = fibs.clone();
+            RSeq::cons(
+                0u64,
+                RSeq::cons(1, RSeq::defer(move || &fibs + &fibs.skip(1))),
+            )
+        })
+        .counted();
+        assert_eq!(fibs.nth(30), 832_040);
+        assert_eq!(counter.count(), 31);
+    }
+}
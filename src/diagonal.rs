@@ -0,0 +1,136 @@
+use alloc::{rc::Rc, vec::Vec};
+use core::cell::RefCell;
+
+use crate::seq::RSeq;
+
+/// Prefixes of two sequences, shared by every element computed from them so
+/// that continuing a computation doesn't copy them.
+pub(crate) type Prefixes<T, U> = Rc<RefCell<(Vec<T>, Vec<U>)>>;
+
+/// Fair enumerations of two-dimensional families, walking anti-diagonals so
+/// that every position is reached after finitely many steps.
+impl<T> RSeq<T>
+where
+    T: Clone + 'static,
+{
+    /// Every pair `(self[i], other[j])`, ordered by anti-diagonal `i + j` and
+    /// then by `i`: `(0, 0), (0, 1), (1, 0), (0, 2), (1, 1), (2, 0), ...`.
+    ///
+    /// The elements read so far are kept, so each pair costs `O(1)`.
+    pub fn product_diagonal<U>(&self, other: &RSeq<U>) -> RSeq<(T, U)>
+    where
+        U: Clone + 'static,
+    {
+        let prefixes = Rc::new(RefCell::new((Vec::new(), Vec::new())));
+        Self::pairs_from(self.clone(), other.clone(), prefixes, 0, 0)
+    }
+
+    /// Continues at position `i` of anti-diagonal `d`, drawn from the shared
+    /// prefixes. `left` and `right` start at element `d` of each input, which
+    /// joins the prefixes as the anti-diagonal starts.
+    fn pairs_from<U>(
+        left: Self,
+        right: RSeq<U>,
+        prefixes: Prefixes<T, U>,
+        d: usize,
+        i: usize,
+    ) -> RSeq<(T, U)>
+    where
+        U: Clone + 'static,
+    {
+        RSeq::lazy(move || {
+            if prefixes.borrow().0.len() == d {
+                let heads = (left.head().clone(), right.head().clone());
+                let (left_prefix, right_prefix) = &mut *prefixes.borrow_mut();
+                left_prefix.push(heads.0);
+                right_prefix.push(heads.1);
+            }
+            let pair = {
+                let (left_prefix, right_prefix) = &*prefixes.borrow();
+                (left_prefix[i].clone(), right_prefix[d - i].clone())
+            };
+            let rest = if i == d {
+                Self::pairs_from(left.tail(), right.tail(), prefixes, d + 1, 0)
+            } else {
+                Self::pairs_from(left, right, prefixes, d, i + 1)
+            };
+            (pair, rest)
+        })
+    }
+}
+
+impl<T> RSeq<RSeq<T>>
+where
+    T: Clone + 'static,
+{
+    /// Flattens a sequence of sequences so that every element of every inner
+    /// sequence appears: element `j` of inner sequence `k` is produced on
+    /// anti-diagonal `k + j`, and each anti-diagonal runs from the first inner
+    /// sequence to the newest.
+    pub fn flatten_diagonal(self) -> RSeq<T> {
+        Self::dovetail_from(self, Vec::new(), 0)
+    }
+
+    /// Continues at position `i` of the current anti-diagonal, where `active`
+    /// holds the unread remainder of each inner sequence reached so far.
+    fn dovetail_from(outer: Self, mut active: Vec<RSeq<T>>, i: usize) -> RSeq<T> {
+        RSeq::lazy(move || {
+            let (outer, i) = if i == active.len() {
+                active.push(outer.head().clone());
+                (outer.tail(), 0)
+            } else {
+                (outer, i)
+            };
+            let out = active[i].head().clone();
+            active[i] = active[i].tail();
+            (out, Self::dovetail_from(outer, active, i + 1))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn product_diagonal() {
+        let s = RSeq::incr(0);
+        let pairs = s.product_diagonal(&s);
+        assert_eq!(
+            pairs.take(10),
+            vec![
+                (0, 0),
+                (0, 1),
+                (1, 0),
+                (0, 2),
+                (1, 1),
+                (2, 0),
+                (0, 3),
+                (1, 2),
+                (2, 1),
+                (3, 0)
+            ]
+        );
+        let prefix = pairs.take(45);
+        for i in 0..5 {
+            for j in 0..5 {
+                assert!(prefix.contains(&(i, j)));
+            }
+        }
+        let mixed = RSeq::incr(1).product_diagonal(&RSeq::cycle(vec!['a', 'b']).unwrap());
+        assert_eq!(mixed.take(3), vec![(1, 'a'), (1, 'b'), (2, 'a')]);
+    }
+
+    #[test]
+    fn flatten_diagonal() {
+        let s = RSeq::incr(0)
+            .map(|i| RSeq::incr(i * 100))
+            .flatten_diagonal();
+        let prefix = s.take(10);
+        assert_eq!(prefix, vec![0, 1, 100, 2, 101, 200, 3, 102, 201, 300]);
+        for k in 0..4 {
+            assert!(prefix.iter().any(|n| n / 100 == k));
+        }
+        assert!(s.find_within(100, |&n| n == 503).is_some());
+    }
+}
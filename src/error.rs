@@ -0,0 +1,45 @@
+use core::fmt;
+
+/// Errors returned by fallible sequence constructors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The input had no elements to build a sequence from.
+    EmptyInput,
+    /// Two inputs that must have the same length did not.
+    LengthMismatch { expected: usize, found: usize },
+    /// An index was not below the length it had to be within.
+    OutOfRange { index: usize, len: usize },
+    /// A row of transition probabilities has a negative entry or does not
+    /// sum to one.
+    NotStochastic { row: usize },
+    /// A fraction had a zero denominator.
+    ZeroDenominator,
+    /// A number base was less than two.
+    InvalidBase { base: u32 },
+    /// A step between dates was zero or not a whole number of days.
+    InvalidDateStep,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::EmptyInput => write!(f, "input must not be empty"),
+            Error::LengthMismatch { expected, found } => {
+                write!(f, "expected {expected} elements, found {found}")
+            }
+            Error::OutOfRange { index, len } => {
+                write!(f, "index {index} is out of range for length {len}")
+            }
+            Error::NotStochastic { row } => {
+                write!(f, "row {row} is not a probability distribution")
+            }
+            Error::ZeroDenominator => write!(f, "denominator must not be zero"),
+            Error::InvalidBase { base } => write!(f, "base {base} is less than 2"),
+            Error::InvalidDateStep => {
+                write!(f, "date step must be a non-zero whole number of days")
+            }
+        }
+    }
+}
+
+impl core::error::Error for Error {}
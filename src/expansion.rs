@@ -0,0 +1,217 @@
+use num_traits::Float;
+
+use crate::{error::Error, seq::RSeq};
+
+impl RSeq<u8> {
+    /// The decimal digits after the point of `numerator / denominator`,
+    /// produced by long division one digit per element. The integer part,
+    /// `numerator / denominator`, is not included.
+    ///
+    /// A terminating expansion continues with zeros, and a repeating one
+    /// repeats forever.
+    ///
+    /// Returns [`Error::ZeroDenominator`] if `denominator` is zero.
+    pub fn decimal_expansion(numerator: u64, denominator: u64) -> Result<Self, Error> {
+        if denominator == 0 {
+            return Err(Error::ZeroDenominator);
+        }
+        Ok(Self::digits_from(numerator % denominator, denominator))
+    }
+
+    /// Continues a long division whose current remainder is `rem`.
+    fn digits_from(rem: u64, denominator: u64) -> Self {
+        Self::lazy(move || {
+            // `rem < denominator`, so the widened product can't overflow and
+            // the next remainder fits back into a `u64`.
+            let shifted = u128::from(rem) * 10;
+            let denominator_wide = u128::from(denominator);
+            let digit = (shifted / denominator_wide) as u8;
+            let rem = (shifted % denominator_wide) as u64;
+            (digit, Self::digits_from(rem, denominator))
+        })
+    }
+}
+
+impl RSeq<u64> {
+    /// The partial quotients `a0; a1, a2, ...` of the continued fraction of
+    /// `x`, produced by the Gauss map: each element is the floor of the
+    /// remaining value, whose fractional part is inverted to give the next.
+    ///
+    /// Rounding errors grow with every step, so only about the first 15
+    /// quotients can be trusted. If the remaining fraction becomes exactly
+    /// zero the expansion has ended and every later quotient is 0; no
+    /// quotient after `a0` is otherwise zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is negative, infinite or NaN.
+    pub fn continued_fraction(x: f64) -> Self {
+        assert!(
+            x.is_finite() && x >= 0.0,
+            "continued fraction of a negative or non-finite number"
+        );
+        Self::quotients_from(x)
+    }
+
+    fn quotients_from(x: f64) -> Self {
+        Self::lazy(move || {
+            let whole = Float::floor(x);
+            let frac = x - whole;
+            let next = if frac == 0.0 { 0.0 } else { frac.recip() };
+            (whole as u64, Self::quotients_from(next))
+        })
+    }
+
+    /// The exact partial quotients of the continued fraction of `p / q`,
+    /// found by the Euclidean algorithm. The expansion of a rational is
+    /// finite, so once it ends every later quotient is 0, as in
+    /// [`RSeq::continued_fraction`].
+    ///
+    /// Returns [`Error::ZeroDenominator`] if `q` is zero.
+    pub fn continued_fraction_rational(p: u64, q: u64) -> Result<Self, Error> {
+        if q == 0 {
+            return Err(Error::ZeroDenominator);
+        }
+        Ok(Self::euclid_from(p, q))
+    }
+
+    fn euclid_from(p: u64, q: u64) -> Self {
+        Self::lazy(move || match p.checked_div(q) {
+            Some(quotient) => (quotient, Self::euclid_from(q, p % q)),
+            None => (0, Self::euclid_from(0, 0)),
+        })
+    }
+}
+
+impl RSeq<u32> {
+    /// The base-`base` digits of the fractional part of `x`, `x - floor(x)`.
+    /// Each element multiplies the remaining fraction by `base` and takes the
+    /// floor as the digit.
+    ///
+    /// Only the first `53 / log2(base)` or so digits carry information about
+    /// `x`; a fraction that reaches exactly zero continues with zeros.
+    ///
+    /// Returns [`Error::InvalidBase`] if `base` is less than 2.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is infinite or NaN.
+    pub fn base_expansion(x: f64, base: u32) -> Result<Self, Error> {
+        assert!(x.is_finite(), "base expansion of a non-finite number");
+        if base < 2 {
+            return Err(Error::InvalidBase { base });
+        }
+        Ok(Self::base_digits_from(x - Float::floor(x), base))
+    }
+
+    fn base_digits_from(frac: f64, base: u32) -> Self {
+        Self::lazy(move || {
+            let shifted = frac * f64::from(base);
+            let whole = Float::floor(shifted);
+            // Rounding can push a fraction just below one up to `base`.
+            let digit = (whole as u32).min(base - 1);
+            (digit, Self::base_digits_from(shifted - whole, base))
+        })
+    }
+
+    /// The fraction `0.d0 d1 d2 ...` in base `base` given by the first `n`
+    /// digits of `digits`, undoing [`RSeq::base_expansion`] up to rounding.
+    pub fn from_base_expansion(digits: &Self, base: u32, n: usize) -> f64 {
+        let base = f64::from(base);
+        digits
+            .take(n)
+            .into_iter()
+            .rev()
+            .fold(0.0, |acc, d| (acc + f64::from(d)) / base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, Error};
+
+    #[test]
+    fn decimal_expansion() {
+        let third = RSeq::decimal_expansion(1, 3).unwrap();
+        assert!(third.take(50).iter().all(|&d| d == 3));
+        let seventh = RSeq::decimal_expansion(1, 7).unwrap();
+        assert_eq!(seventh.take(12), vec![1, 4, 2, 8, 5, 7, 1, 4, 2, 8, 5, 7]);
+        assert_eq!(
+            RSeq::decimal_expansion(1, 8).unwrap().take(6),
+            vec![1, 2, 5, 0, 0, 0]
+        );
+        assert_eq!(
+            RSeq::decimal_expansion(22, 7).unwrap().take(8),
+            vec![1, 4, 2, 8, 5, 7, 1, 4]
+        );
+        assert!(RSeq::decimal_expansion(u64::MAX - 1, u64::MAX)
+            .unwrap()
+            .take(5)
+            .iter()
+            .all(|&d| d == 9));
+        assert_eq!(
+            RSeq::decimal_expansion(1, 0).err(),
+            Some(Error::ZeroDenominator)
+        );
+    }
+
+    #[test]
+    fn continued_fraction() {
+        let phi = (1.0 + 5f64.sqrt()) / 2.0;
+        assert_eq!(RSeq::continued_fraction(phi).take(10), vec![1; 10]);
+        let root2 = RSeq::continued_fraction(2f64.sqrt());
+        assert_eq!(root2.take(10), vec![1, 2, 2, 2, 2, 2, 2, 2, 2, 2]);
+        assert_eq!(RSeq::continued_fraction(2.5).take(4), vec![2, 2, 0, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "negative or non-finite")]
+    fn continued_fraction_nan() {
+        RSeq::continued_fraction(f64::NAN);
+    }
+
+    #[test]
+    fn continued_fraction_rational() {
+        assert_eq!(
+            RSeq::continued_fraction_rational(355, 113).unwrap().take(5),
+            vec![3, 7, 16, 0, 0]
+        );
+        assert_eq!(
+            RSeq::continued_fraction_rational(0, 5).unwrap().take(2),
+            vec![0, 0]
+        );
+        assert_eq!(
+            RSeq::continued_fraction_rational(1, 0).err(),
+            Some(Error::ZeroDenominator)
+        );
+    }
+
+    #[test]
+    fn base_expansion() {
+        assert_eq!(
+            RSeq::base_expansion(0.5, 2).unwrap().take(4),
+            vec![1, 0, 0, 0]
+        );
+        assert_eq!(
+            RSeq::base_expansion(1.0 / 3.0, 3).unwrap().take(3),
+            vec![1, 0, 0]
+        );
+        assert_eq!(
+            RSeq::base_expansion(-0.25, 10).unwrap().take(3),
+            vec![7, 5, 0]
+        );
+        assert_eq!(
+            RSeq::base_expansion(0.5, 1).err(),
+            Some(Error::InvalidBase { base: 1 })
+        );
+    }
+
+    #[test]
+    fn from_base_expansion() {
+        let digits = RSeq::base_expansion(0.1, 2).unwrap();
+        assert_eq!(digits.take(6), vec![0, 0, 0, 1, 1, 0]);
+        assert!((RSeq::from_base_expansion(&digits, 2, 20) - 0.1).abs() < 1e-6);
+        let digits = RSeq::base_expansion(0.8125, 16).unwrap();
+        assert_eq!(RSeq::from_base_expansion(&digits, 16, 5), 0.8125);
+    }
+}
@@ -0,0 +1,472 @@
+use alloc::{rc::Rc, vec::Vec};
+#[cfg(feature = "std")]
+use std::{
+    cell::RefCell,
+    io::{self, BufRead},
+    string::String,
+};
+
+use crate::{seq::RSeq, thunk::Thunk};
+
+/// A lazily evaluated sequence that may end.
+///
+/// Like [`RSeq`](crate::RSeq), each element is computed at most once, when
+/// first demanded, and clones share that work; unlike it, the sequence can
+/// run out of elements.
+pub struct FSeq<T> {
+    node: Rc<Thunk<Option<Node<T>>>>,
+}
+
+struct Node<T> {
+    head: T,
+    tail: FSeq<T>,
+}
+
+impl<T> Clone for FSeq<T> {
+    fn clone(&self) -> Self {
+        Self {
+            node: Rc::clone(&self.node),
+        }
+    }
+}
+
+impl<T> Drop for FSeq<T> {
+    fn drop(&mut self) {
+        // Unlink forced nodes one at a time, as `RSeq` does.
+        let mut next = Rc::get_mut(&mut self.node).and_then(Thunk::take).flatten();
+        while let Some(mut node) = next {
+            next = Rc::get_mut(&mut node.tail.node)
+                .and_then(Thunk::take)
+                .flatten();
+        }
+    }
+}
+
+impl<T> FSeq<T> {
+    /// Builds a sequence whose first element and rest are computed by `f`
+    /// when first demanded, or which ends there if `f` returns `None`.
+    pub(crate) fn lazy(f: impl FnOnce() -> Option<(T, Self)> + Clone + 'static) -> Self {
+        Self {
+            node: Rc::new(Thunk::new(move || {
+                f().map(|(head, tail)| Node { head, tail })
+            })),
+        }
+    }
+
+    /// The sequence with no elements.
+    pub fn empty() -> Self {
+        Self {
+            node: Rc::new(Thunk::ready(None)),
+        }
+    }
+
+    /// Puts `head` in front of `tail` without forcing anything in `tail`.
+    pub fn cons(head: T, tail: Self) -> Self {
+        Self {
+            node: Rc::new(Thunk::ready(Some(Node { head, tail }))),
+        }
+    }
+
+    /// Returns the first element, computing it if needed, or `None` if the
+    /// sequence is empty.
+    pub fn head(&self) -> Option<&T> {
+        self.node.force().as_ref().map(|node| &node.head)
+    }
+
+    /// Returns the sequence following the head, or `None` if the sequence is
+    /// empty. The tail's own elements are not computed until they are
+    /// demanded.
+    pub fn tail(&self) -> Option<Self> {
+        self.node.force().as_ref().map(|node| node.tail.clone())
+    }
+
+    /// Whether the sequence has no elements. Computes the head.
+    pub fn is_empty(&self) -> bool {
+        self.node.force().is_none()
+    }
+}
+
+impl<T> FSeq<T>
+where
+    T: Clone + 'static,
+{
+    /// The sequence `start, f(start), f(f(start)), ...`, ending after the
+    /// last element for which `f` returns `None`.
+    pub fn unfold_until(start: T, f: impl Fn(T) -> Option<T> + 'static) -> Self {
+        Self::unfold_until_rc(Some(start), Rc::new(f))
+    }
+
+    fn unfold_until_rc(start: Option<T>, f: Rc<dyn Fn(T) -> Option<T>>) -> Self {
+        Self::lazy(move || {
+            let start = start?;
+            let next = f(start.clone());
+            Some((start, Self::unfold_until_rc(next, f)))
+        })
+    }
+
+    /// Collects the first `n` elements into a `Vec`, or every element if
+    /// there are fewer than `n`.
+    pub fn take(&self, n: usize) -> Vec<T> {
+        self.iter().take(n).collect()
+    }
+
+    /// Applies `f` to every element.
+    pub fn map<U>(&self, f: impl Fn(T) -> U + 'static) -> FSeq<U>
+    where
+        U: Clone + 'static,
+    {
+        self.map_rc(Rc::new(f))
+    }
+
+    fn map_rc<U>(&self, f: Rc<dyn Fn(T) -> U>) -> FSeq<U>
+    where
+        U: Clone + 'static,
+    {
+        let seq = self.clone();
+        FSeq::lazy(move || {
+            let head = f(seq.head()?.clone());
+            Some((head, seq.tail()?.map_rc(f)))
+        })
+    }
+
+    /// Yields the elements of `self`, then those of `other`. Nothing in
+    /// `other` is forced until `self` has run out.
+    pub fn append(&self, other: &Self) -> Self {
+        let (seq, other) = (self.clone(), other.clone());
+        Self::lazy(move || match seq.head() {
+            Some(head) => Some((head.clone(), seq.tail()?.append(&other))),
+            None => Some((other.head()?.clone(), other.tail()?)),
+        })
+    }
+
+    /// The number of elements. Forces every one of them.
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Collects every element into a `Vec`.
+    pub fn collect(&self) -> Vec<T> {
+        self.iter().collect()
+    }
+
+    /// Yields the elements of the sequence, then `pad` forever.
+    pub fn into_rseq_padded(self, pad: T) -> RSeq<T> {
+        RSeq::lazy(move || match self.node.force() {
+            Some(node) => (node.head.clone(), node.tail.clone().into_rseq_padded(pad)),
+            None => (pad.clone(), RSeq::cnst(pad)),
+        })
+    }
+
+    /// Iterates over the sequence without consuming it. Elements forced by
+    /// the iterator stay cached in the sequence.
+    pub fn iter(&self) -> FSeqIter<T> {
+        FSeqIter { curr: self.clone() }
+    }
+}
+
+/// Where [`FSeq::from_lines`] leaves the read error that ended its
+/// sequence, if any. Clones share the same slot.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default)]
+pub struct LinesError {
+    error: Rc<RefCell<Option<io::Error>>>,
+}
+
+#[cfg(feature = "std")]
+impl LinesError {
+    /// Whether reading has stopped at an error that hasn't been taken yet.
+    pub fn is_set(&self) -> bool {
+        self.error.borrow().is_some()
+    }
+
+    /// Removes and returns the error, if reading has stopped at one.
+    pub fn take(&self) -> Option<io::Error> {
+        self.error.borrow_mut().take()
+    }
+}
+
+#[cfg(feature = "std")]
+impl FSeq<String> {
+    /// The lines of `reader`, without their line endings, each read the first
+    /// time it is demanded. The sequence ends at the end of the input.
+    ///
+    /// A read error, including a line that isn't valid UTF-8, also ends the
+    /// sequence. It is stored in the returned [`LinesError`], so that callers
+    /// can tell it apart from the end of the input.
+    pub fn from_lines<R: BufRead + 'static>(reader: R) -> (Self, LinesError) {
+        let error = LinesError::default();
+        let lines = Self::lines_from(Rc::new(RefCell::new(reader.lines())), error.clone());
+        (lines, error)
+    }
+
+    fn lines_from<R: BufRead + 'static>(
+        lines: Rc<RefCell<io::Lines<R>>>,
+        error: LinesError,
+    ) -> Self {
+        Self::lazy(move || {
+            let line = lines.borrow_mut().next()?;
+            match line {
+                Ok(line) => Some((line, Self::lines_from(lines, error))),
+                Err(e) => {
+                    *error.error.borrow_mut() = Some(e);
+                    None
+                }
+            }
+        })
+    }
+}
+
+impl<T> RSeq<T>
+where
+    T: Clone + 'static,
+{
+    /// The finite sequence of the first `n` elements. Nothing is forced until
+    /// the result is.
+    pub fn truncate(&self, n: usize) -> FSeq<T> {
+        let seq = self.clone();
+        FSeq::lazy(move || {
+            if n == 0 {
+                return None;
+            }
+            Some((seq.head().clone(), seq.tail().truncate(n - 1)))
+        })
+    }
+}
+
+/// An iterator over the elements of an [`FSeq`].
+pub struct FSeqIter<T> {
+    curr: FSeq<T>,
+}
+
+impl<T: Clone + 'static> Iterator for FSeqIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let out = self.curr.head()?.clone();
+        self.curr = self.curr.tail()?;
+        Some(out)
+    }
+}
+
+impl<T: Clone + 'static> IntoIterator for FSeq<T> {
+    type Item = T;
+
+    type IntoIter = FSeqIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        FSeqIter { curr: self }
+    }
+}
+
+impl<T: Clone + 'static> IntoIterator for &FSeq<T> {
+    type Item = T;
+
+    type IntoIter = FSeqIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    #[cfg(feature = "std")]
+    use std::{
+        io::{self, BufRead, Cursor},
+        rc::Rc,
+    };
+
+    use crate::prelude::*;
+
+    fn collatz(start: u64) -> FSeq<u64> {
+        FSeq::unfold_until(start, |n| match n {
+            1 => None,
+            n if n % 2 == 0 => Some(n / 2),
+            n => Some(3 * n + 1),
+        })
+    }
+
+    #[test]
+    fn unfold_until() {
+        let s = collatz(27);
+        let trajectory: Vec<_> = s.iter().collect();
+        assert_eq!(trajectory.len(), 112);
+        assert_eq!(trajectory[..4], [27, 82, 41, 124]);
+        assert_eq!(trajectory.last(), Some(&1));
+        assert_eq!(collatz(1).take(5), vec![1]);
+        assert_eq!(collatz(6).take(3), vec![6, 3, 10]);
+    }
+
+    #[test]
+    fn iterator_ends() {
+        let mut iter = collatz(4).into_iter();
+        assert_eq!(iter.next(), Some(4));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert!(FSeq::<i32>::empty().iter().next().is_none());
+    }
+
+    #[test]
+    fn cons() {
+        let s = FSeq::cons(1, FSeq::cons(2, FSeq::empty()));
+        assert_eq!(s.head(), Some(&1));
+        assert_eq!(s.tail().unwrap().take(5), vec![2]);
+        assert!(s.tail().unwrap().tail().unwrap().is_empty());
+        assert!(s.tail().unwrap().tail().unwrap().tail().is_none());
+    }
+
+    #[test]
+    fn map() {
+        thread_local! {
+            static CALLS: Cell<usize> = const { Cell::new(0) };
+        }
+        let s = collatz(27).map(|n| {
+            CALLS.with(|c| c.set(c.get() + 1));
+            n * 2
+        });
+        assert_eq!(s.take(3), vec![54, 164, 82]);
+        assert_eq!(CALLS.with(Cell::get), 3);
+        assert_eq!(s.iter().count(), 112);
+        assert_eq!(s.iter().last(), Some(2));
+        assert_eq!(CALLS.with(Cell::get), 112);
+    }
+
+    #[test]
+    fn drop_long_chain() {
+        let s = FSeq::unfold_until(0, |n| (n < 1_000_000).then_some(n + 1));
+        assert_eq!(s.iter().count(), 1_000_001);
+        drop(s);
+    }
+
+    #[test]
+    fn append() {
+        let s = FSeq::cons(1, FSeq::cons(2, FSeq::empty()));
+        let t = collatz(4);
+        assert_eq!(s.append(&t).collect(), vec![1, 2, 4, 2, 1]);
+        assert_eq!(t.append(&s).collect(), vec![4, 2, 1, 1, 2]);
+        assert_eq!(s.append(&t).len(), s.len() + t.len());
+        assert_eq!(FSeq::empty().append(&s).collect(), vec![1, 2]);
+        assert!(FSeq::<i32>::empty().append(&FSeq::empty()).is_empty());
+        let lazy = s.append(&FSeq::lazy(|| panic!("other forced")));
+        assert_eq!(lazy.take(2), vec![1, 2]);
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(collatz(27).len(), 112);
+        assert_eq!(FSeq::<i32>::empty().len(), 0);
+    }
+
+    #[test]
+    fn truncate_and_pad() {
+        let s = RSeq::incr(0).map(|n| n * n);
+        let prefix = s.truncate(4);
+        assert_eq!(prefix.collect(), vec![0, 1, 4, 9]);
+        assert_eq!(RSeq::incr(0).truncate(0).len(), 0);
+        let padded = prefix.into_rseq_padded(-1);
+        assert_eq!(padded.take(6), vec![0, 1, 4, 9, -1, -1]);
+        assert!(padded.eq_prefix(&s, 4));
+        let lazy = RSeq::cons(1, RSeq::lazy(|| panic!("forced past the cut")));
+        assert_eq!(lazy.truncate(1).collect(), vec![1]);
+    }
+
+    #[test]
+    fn measured_data_before_model() {
+        let measured = FSeq::cons(3.0, FSeq::cons(2.5, FSeq::empty()));
+        let model = RSeq::geometric(2.0, 0.5);
+        let combined = measured.append(&model.truncate(3)).into_rseq_padded(0.0);
+        assert_eq!(
+            (&combined * 2.0).take(7),
+            vec![6.0, 5.0, 4.0, 2.0, 1.0, 0.0, 0.0]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn from_lines() {
+        let text = "first\nsecond\r\n\nlast";
+        let (lines, error) = FSeq::from_lines(Cursor::new(text.as_bytes()));
+        assert_eq!(lines.head().map(String::as_str), Some("first"));
+        assert_eq!(lines.collect(), vec!["first", "second", "", "last"]);
+        assert_eq!(lines.take(2), vec!["first", "second"]);
+        assert!(!error.is_set());
+        assert!(FSeq::from_lines(Cursor::new(&b""[..])).0.is_empty());
+        let (invalid, error) = FSeq::from_lines(Cursor::new(&b"ok\n\xff\nafter"[..]));
+        assert_eq!(invalid.collect(), vec!["ok"]);
+        assert_eq!(
+            error.take().map(|e| e.kind()),
+            Some(io::ErrorKind::InvalidData)
+        );
+        assert!(error.take().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn from_lines_failing_reader() {
+        struct Failing {
+            inner: Cursor<&'static [u8]>,
+        }
+        impl io::Read for Failing {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                match io::Read::read(&mut self.inner, buf)? {
+                    0 => Err(io::Error::new(io::ErrorKind::BrokenPipe, "gone")),
+                    n => Ok(n),
+                }
+            }
+        }
+        let reader = io::BufReader::with_capacity(
+            4,
+            Failing {
+                inner: Cursor::new(&b"one\ntwo\nthr"[..]),
+            },
+        );
+        let (lines, error) = FSeq::from_lines(reader);
+        assert_eq!(lines.take(2), vec!["one", "two"]);
+        assert!(!error.is_set());
+        assert_eq!(lines.collect(), vec!["one", "two"]);
+        let error = error.take().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::BrokenPipe);
+        assert_eq!(error.to_string(), "gone");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn from_lines_reads_once() {
+        struct Counting<R> {
+            inner: R,
+            consumed: Rc<Cell<usize>>,
+        }
+        impl<R: io::Read> io::Read for Counting<R> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                self.inner.read(buf)
+            }
+        }
+        impl<R: BufRead> BufRead for Counting<R> {
+            fn fill_buf(&mut self) -> io::Result<&[u8]> {
+                self.inner.fill_buf()
+            }
+            fn consume(&mut self, amt: usize) {
+                self.consumed.set(self.consumed.get() + amt);
+                self.inner.consume(amt);
+            }
+        }
+        let consumed = Rc::new(Cell::new(0));
+        let reader = Counting {
+            inner: Cursor::new(&b"a\nbb\nccc\n"[..]),
+            consumed: Rc::clone(&consumed),
+        };
+        let (lines, _) = FSeq::from_lines(reader);
+        assert_eq!(consumed.get(), 0);
+        assert_eq!(lines.take(2), vec!["a", "bb"]);
+        assert_eq!(consumed.get(), 5);
+        assert_eq!(lines.take(2), vec!["a", "bb"]);
+        assert_eq!(lines.tail().unwrap().take(1), vec!["bb"]);
+        assert_eq!(consumed.get(), 5);
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines.len(), 3);
+        assert_eq!(consumed.get(), 9);
+    }
+}
@@ -0,0 +1,176 @@
+use core::fmt::{self, Debug, Display, Formatter};
+#[cfg(feature = "std")]
+use std::io;
+
+use crate::seq::RSeq;
+
+/// How many elements `Debug` and `Display` force and show for an [`RSeq`].
+const PREVIEW_LEN: usize = 5;
+
+/// Formats the first few elements of a sequence, forcing no others. Created
+/// by [`RSeq::preview`].
+pub struct Preview<'a, T> {
+    seq: &'a RSeq<T>,
+    n: usize,
+}
+
+impl<T> RSeq<T> {
+    /// Returns a wrapper that formats the first `n` elements, like
+    /// `RSeq[0, 1, 2, ...]`.
+    pub fn preview(&self, n: usize) -> Preview<'_, T> {
+        Preview { seq: self, n }
+    }
+}
+
+impl<T> Preview<'_, T> {
+    fn write(
+        &self,
+        f: &mut Formatter<'_>,
+        elem: impl Fn(&T, &mut Formatter<'_>) -> fmt::Result,
+    ) -> fmt::Result {
+        f.write_str("RSeq[")?;
+        let mut curr = self.seq.clone();
+        for i in 0..self.n {
+            if i > 0 {
+                curr = curr.tail();
+            }
+            elem(curr.head(), f)?;
+            f.write_str(", ")?;
+        }
+        f.write_str("...]")
+    }
+}
+
+impl<T: Debug> Debug for Preview<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.write(f, |x, f| x.fmt(f))
+    }
+}
+
+impl<T: Display> Display for Preview<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.write(f, |x, f| x.fmt(f))
+    }
+}
+
+impl<T: Debug> Debug for RSeq<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.preview(PREVIEW_LEN).fmt(f)
+    }
+}
+
+impl<T: Display> Display for RSeq<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.preview(PREVIEW_LEN), f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Display> RSeq<T> {
+    /// Writes the first `n` elements to `w`, separated by `sep` but with
+    /// none after the last, then flushes `w`. Elements are written as they
+    /// are computed.
+    pub fn write_prefix<W: io::Write>(&self, n: usize, w: &mut W, sep: &str) -> io::Result<()> {
+        for (i, seq) in self.suffixes().take(n).enumerate() {
+            if i > 0 {
+                w.write_all(sep.as_bytes())?;
+            }
+            write!(w, "{}", seq.head())?;
+        }
+        w.flush()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A: Display, B: Display> RSeq<(A, B)> {
+    /// Writes the first `n` pairs to `w` as comma-separated rows, each ending
+    /// in a newline, then flushes `w`.
+    pub fn write_prefix_csv<W: io::Write>(&self, n: usize, w: &mut W) -> io::Result<()> {
+        for seq in self.suffixes().take(n) {
+            let (a, b) = seq.head();
+            writeln!(w, "{a},{b}")?;
+        }
+        w.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "std")]
+    use std::io::{self, Write};
+
+    use crate::prelude::*;
+
+    #[test]
+    fn debug() {
+        let s = RSeq::incr(0);
+        assert_eq!(format!("{s:?}"), "RSeq[0, 1, 2, 3, 4, ...]");
+        assert_eq!(format!("{:?}", s.preview(2)), "RSeq[0, 1, ...]");
+        assert_eq!(format!("{:?}", s.preview(0)), "RSeq[...]");
+        let strs = RSeq::cnst("a");
+        assert_eq!(format!("{:?}", strs.preview(2)), r#"RSeq["a", "a", ...]"#);
+        assert_eq!(format!("{strs}"), "RSeq[a, a, a, a, a, ...]");
+    }
+
+    #[test]
+    fn debug_forces_only_preview() {
+        let s = RSeq::incr(0).map(|n| {
+            assert!(n < 5, "sixth element forced");
+            n
+        });
+        assert_eq!(format!("{s:?}"), "RSeq[0, 1, 2, 3, 4, ...]");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn write_prefix() {
+        let mut out = Vec::new();
+        RSeq::incr(1).write_prefix(4, &mut out, "\n").unwrap();
+        assert_eq!(out, b"1\n2\n3\n4");
+        out.clear();
+        RSeq::geometric(1.0, 0.5)
+            .write_prefix(3, &mut out, ",")
+            .unwrap();
+        assert_eq!(out, b"1,0.5,0.25");
+        out.clear();
+        let untouched = RSeq::<i32>::lazy(|| panic!("head forced"));
+        untouched.write_prefix(0, &mut out, ",").unwrap();
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn write_prefix_csv() {
+        let mut out = Vec::new();
+        let rows = RSeq::zip(&RSeq::incr(0), &RSeq::incr(0).map(|n| n * n));
+        rows.write_prefix_csv(3, &mut out).unwrap();
+        assert_eq!(out, b"0,0\n1,1\n2,4\n");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn write_prefix_error() {
+        struct Full(usize);
+        impl Write for Full {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                if self.0 == 0 {
+                    return Err(io::Error::other("full"));
+                }
+                let n = buf.len().min(self.0);
+                self.0 -= n;
+                Ok(n)
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+        let s = RSeq::incr(0).map(|n| {
+            assert!(n < 3, "forced past the failed write");
+            n
+        });
+        let err = s.write_prefix(10, &mut Full(3), ",").unwrap_err();
+        assert_eq!(err.to_string(), "full");
+        assert!(s.write_prefix(2, &mut Full(3), ",").is_ok());
+    }
+}
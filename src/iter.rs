@@ -0,0 +1,229 @@
+use alloc::{rc::Rc, vec::Vec};
+use core::cell::RefCell;
+
+use crate::seq::RSeq;
+
+/// An iterator over the elements of an [`RSeq`]. It never returns `None`.
+pub struct RSeqIter<T> {
+    curr: RSeq<T>,
+}
+
+impl<T> RSeqIter<T> {
+    fn new(start: RSeq<T>) -> Self {
+        Self { curr: start }
+    }
+}
+
+impl<T: Clone + 'static> Iterator for RSeqIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let out = self.curr.head().clone();
+        self.curr = self.curr.tail();
+        Some(out)
+    }
+}
+
+/// An iterator over the first elements of an [`RSeq`], created by
+/// [`RSeq::take_iter`].
+pub struct TakeIter<T> {
+    inner: RSeqIter<T>,
+    remaining: usize,
+}
+
+impl<T: Clone + 'static> Iterator for TakeIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: Clone + 'static> ExactSizeIterator for TakeIter<T> {}
+
+impl<T: Clone + 'static> IntoIterator for RSeq<T> {
+    type Item = T;
+
+    type IntoIter = RSeqIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        RSeqIter::new(self)
+    }
+}
+
+impl<T: Clone + 'static> IntoIterator for &RSeq<T> {
+    type Item = T;
+
+    type IntoIter = RSeqIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Clone + 'static> RSeq<T> {
+    /// Iterates over the sequence without consuming it. Elements forced by
+    /// the iterator stay cached in the sequence.
+    pub fn iter(&self) -> RSeqIter<T> {
+        RSeqIter::new(self.clone())
+    }
+
+    /// Returns `k` independent iterators over the sequence. Each element is
+    /// still computed once, however many iterators reach it, and is freed
+    /// once every iterator and every other handle on the sequence has passed
+    /// it.
+    pub fn tee(&self, k: usize) -> Vec<RSeqIter<T>> {
+        (0..k).map(|_| self.iter()).collect()
+    }
+
+    /// Iterates over the first `n` elements without collecting them. No
+    /// element past them is forced.
+    pub fn take_iter(&self, n: usize) -> TakeIter<T> {
+        TakeIter {
+            inner: self.iter(),
+            remaining: n,
+        }
+    }
+}
+
+/// Lifts an infinite iterator into an [`RSeq`].
+pub trait IntoRSeq: Iterator + Sized {
+    /// Converts the iterator into a sequence that pulls the next item from it
+    /// the first time each element is demanded. Demanding the same element
+    /// again reuses the item already pulled.
+    ///
+    /// # Panics
+    ///
+    /// Demanding an element panics if the iterator has run out of items.
+    fn into_rseq(self) -> RSeq<Self::Item>;
+}
+
+impl<I> IntoRSeq for I
+where
+    I: Iterator + 'static,
+    I::Item: 'static,
+{
+    fn into_rseq(self) -> RSeq<Self::Item> {
+        from_shared(Rc::new(RefCell::new(self)))
+    }
+}
+
+fn from_shared<I>(iter: Rc<RefCell<I>>) -> RSeq<I::Item>
+where
+    I: Iterator + 'static,
+    I::Item: 'static,
+{
+    RSeq::lazy(move || {
+        let head = iter
+            .borrow_mut()
+            .next()
+            .expect("iterator lifted into an RSeq ran out of items");
+        (head, from_shared(iter))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::{Cell, RefCell},
+        rc::{Rc, Weak},
+    };
+
+    use crate::prelude::*;
+
+    #[test]
+    fn iterator() {
+        let s = RSeq::incr(0).filter(|n| n % 2 == 0);
+        let iter = s.into_iter();
+        assert_eq!(
+            iter.map(|n| n * n).take(10).collect::<Vec<i32>>(),
+            vec![0, 4, 16, 36, 64, 100, 144, 196, 256, 324]
+        );
+    }
+
+    #[test]
+    fn iter() {
+        let s = RSeq::incr(0).map(|n| n * n);
+        assert_eq!(s.iter().take(4).collect::<Vec<_>>(), vec![0, 1, 4, 9]);
+        assert_eq!(s.iter().take(4).collect::<Vec<_>>(), vec![0, 1, 4, 9]);
+        let mut total = 0;
+        for n in &s {
+            if n > 10 {
+                break;
+            }
+            total += n;
+        }
+        assert_eq!(total, 14);
+        assert_eq!(s.take(3), vec![0, 1, 4]);
+    }
+
+    #[test]
+    fn take_iter() {
+        assert_eq!(RSeq::cnst(1).take_iter(1_000_000).sum::<i32>(), 1_000_000);
+        assert_eq!(RSeq::incr(0).take_iter(0).next(), None);
+        let s = RSeq::incr(0);
+        let iter = s.take_iter(10);
+        assert_eq!(iter.size_hint(), (10, Some(10)));
+        assert_eq!(
+            iter.filter(|n| n % 3 == 0)
+                .map(|n| n * 2)
+                .collect::<Vec<_>>(),
+            vec![0, 6, 12, 18]
+        );
+        let lazy = RSeq::cons(1, RSeq::cons(2, RSeq::lazy(|| panic!("forced too far"))));
+        assert_eq!(lazy.take_iter(2).len(), 2);
+        assert_eq!(lazy.take_iter(2).collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn tee() {
+        thread_local! {
+            static CALLS: Cell<usize> = const { Cell::new(0) };
+            static MADE: RefCell<Vec<Weak<usize>>> = const { RefCell::new(Vec::new()) };
+        }
+        let s = RSeq::from_fn(|n| {
+            CALLS.with(|c| c.set(c.get() + 1));
+            let elem = Rc::new(n);
+            MADE.with(|m| m.borrow_mut().push(Rc::downgrade(&elem)));
+            elem
+        });
+        let mut handles = s.tee(2);
+        drop(s);
+        let fast: Vec<_> = handles[0].by_ref().take(10).map(|n| *n).collect();
+        let slow: Vec<_> = handles[1].by_ref().take(5).map(|n| *n).collect();
+        assert_eq!(fast, (0..10).collect::<Vec<_>>());
+        assert_eq!(slow, (0..5).collect::<Vec<_>>());
+        assert_eq!(CALLS.with(Cell::get), 10);
+        MADE.with(|m| {
+            let m = m.borrow();
+            assert!(m[..5].iter().all(|w| w.upgrade().is_none()));
+            assert!(m[5..].iter().all(|w| w.upgrade().is_some()));
+        });
+    }
+
+    #[test]
+    fn into_rseq() {
+        let s = (0..).map(|n| n * 3).into_rseq();
+        assert_eq!(s.take(5), vec![0, 3, 6, 9, 12]);
+        assert_eq!(*s.tail().head(), 3);
+        assert_eq!(*s.tail().head(), 3);
+        assert_eq!(
+            RSeq::interleave(&s, &(0..).into_rseq()).take(4),
+            vec![0, 0, 3, 1]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "ran out of items")]
+    fn into_rseq_exhausted() {
+        (0..3).into_rseq().take(5);
+    }
+}
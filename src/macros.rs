@@ -0,0 +1,321 @@
+/// Builds a sequence from literal elements.
+///
+/// - `rseq![x; ..]` repeats `x` forever, like [`RSeq::cnst`].
+/// - `rseq![a, b, c; ...]` repeats `a, b, c` in order forever, like
+///   [`RSeq::cycle`].
+/// - `rseq![a, b, c => rest]` yields `a, b, c` and then continues with the
+///   sequence `rest`, like [`RSeq::prepend`].
+///
+/// ```
+/// use rseq::{prelude::*, rseq};
+///
+/// assert_eq!(rseq![7; ..].take(3), vec![7, 7, 7]);
+/// assert_eq!(rseq![1, 2; ...].take(5), vec![1, 2, 1, 2, 1]);
+/// assert_eq!(rseq![10, 20 => RSeq::incr(0)].take(4), vec![10, 20, 0, 1]);
+/// ```
+///
+/// At least one element is required:
+///
+/// ```compile_fail
+/// let empty: rseq::RSeq<i32> = rseq::rseq![; ...];
+/// ```
+///
+/// [`RSeq::cnst`]: crate::RSeq::cnst
+/// [`RSeq::cycle`]: crate::RSeq::cycle
+/// [`RSeq::prepend`]: crate::RSeq::prepend
+#[macro_export]
+macro_rules! rseq {
+    [$x:expr; ..] => {
+        $crate::RSeq::cnst($x)
+    };
+    [$($x:expr),+ $(,)?; ...] => {
+        $crate::RSeq::cycle($crate::__private::vec![$($x),+])
+            .expect("rseq! cycles at least one element")
+    };
+    [$($x:expr),+ $(,)? => $rest:expr] => {
+        $crate::RSeq::prepend(&$rest, $crate::__private::vec![$($x),+])
+    };
+}
+
+/// Defines a sequence by a recurrence relation and its initial terms.
+///
+/// `recurrence!(a[n] = rhs; a[0] = x0, a[1] = x1, ...)` is the sequence
+/// starting with the given terms, each later term `a[n]` being `rhs`. The
+/// right-hand side may use the index `n` and earlier terms `a[n - k]`, for a
+/// literal `k` no larger than the number of initial terms; any other way of
+/// indexing `a` fails to compile.
+///
+/// The result is always an [`RSeq`]. When the right-hand side is a sum of
+/// terms `a[n - k]`, each optionally written `c * a[n - k]` for a literal
+/// coefficient `c`, it is built with
+/// [`RSeq::linear_recurrence`]. Otherwise it is built with
+/// [`RSeq::window_recurrence`], and terms are read out of a window by
+/// reference: copy or clone them as their type requires. To compute a
+/// distant term of a linear recurrence without the ones before it, build a
+/// [`LinearRecurrence`] and use its [`nth`](crate::LinearRecurrence::nth).
+///
+/// ```
+/// use rseq::recurrence;
+///
+/// let fibs = recurrence!(a[n] = a[n - 1] + a[n - 2]; a[0] = 0u64, a[1] = 1);
+/// assert_eq!(fibs.take(8), vec![0, 1, 1, 2, 3, 5, 8, 13]);
+/// let factorials = recurrence!(f[n] = f[n - 1] * n as u64; f[0] = 1);
+/// assert_eq!(factorials.take(6), vec![1, 1, 2, 6, 24, 120]);
+/// ```
+///
+/// Every lag needs an initial term, and terms can only be reached through a
+/// lag:
+///
+/// ```compile_fail
+/// let s = rseq::recurrence!(a[n] = a[n - 1] + a[n - 2]; a[0] = 1);
+/// ```
+///
+/// ```compile_fail
+/// let s = rseq::recurrence!(a[n] = a[n] + 1; a[0] = 0);
+/// ```
+///
+/// [`LinearRecurrence`]: crate::LinearRecurrence
+/// [`RSeq`]: crate::RSeq
+/// [`RSeq::linear_recurrence`]: crate::RSeq::linear_recurrence
+/// [`RSeq::window_recurrence`]: crate::RSeq::window_recurrence
+#[macro_export]
+macro_rules! recurrence {
+    ($a:ident [$n:ident] = $($rest:tt)+) => {
+        $crate::recurrence!(@split $a $n [] $($rest)+)
+    };
+    (@split $a:ident $n:ident [$($rhs:tt)*] ; $($init:tt)+) => {
+        $crate::recurrence!(@linear $a $n [] [$($rhs)*] [$($init)+] $($rhs)*)
+    };
+    (@split $a:ident $n:ident [$($rhs:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::recurrence!(@split $a $n [$($rhs)* $t] $($rest)*)
+    };
+    // Reads the right-hand side as a sum of terms `c * b[m - k]` or
+    // `b[m - k]`, falling back to a window recurrence at anything else.
+    // Whether each `b` is the sequence itself is only known once the
+    // identifiers can be compared, in `@build`.
+    (@linear $a:ident $n:ident $terms:tt $rhs:tt $init:tt
+        $c:tt * $b:ident [$m:ident - $k:tt] $(+ $($rest:tt)+)?) => {
+        $crate::recurrence!(@coeff [$c] ($b $m $k ($c)) $a $n $terms $rhs $init $($($rest)+)?)
+    };
+    (@linear $a:ident $n:ident [$($terms:tt)*] $rhs:tt $init:tt
+        $b:ident [$m:ident - $k:tt] $(+ $($rest:tt)+)?) => {
+        $crate::recurrence!(
+            @linear $a $n [$($terms)* ($b $m $k ($crate::__private::One::one()))] $rhs $init
+            $($($rest)+)?
+        )
+    };
+    (@linear $a:ident $n:ident [$(($b:ident $m:ident $k:tt $c:tt))+] $rhs:tt [$($init:tt)+]) => {
+        $crate::recurrence!(@build $a $n $rhs [$(($b $k $c))+] $($init)+)
+    };
+    (@linear $a:ident $n:ident $terms:tt $rhs:tt [$($init:tt)+] $($other:tt)*) => {
+        $crate::recurrence!(@build $a $n $rhs [] $($init)+)
+    };
+    // Only literal coefficients keep a term linear; anything else, which
+    // may well use `n` or the sequence, needs the window form.
+    (@coeff [$c:literal] $term:tt $a:ident $n:ident [$($terms:tt)*] $rhs:tt $init:tt
+        $($rest:tt)*) => {
+        $crate::recurrence!(@linear $a $n [$($terms)* $term] $rhs $init $($rest)*)
+    };
+    (@coeff [$c:tt] $term:tt $a:ident $n:ident $terms:tt $rhs:tt [$($init:tt)+]
+        $($rest:tt)*) => {
+        $crate::recurrence!(@build $a $n $rhs [] $($init)+)
+    };
+    (
+        @build $a:ident $n:ident [$($rhs:tt)*] [$(($b:ident $k:tt $c:tt))+]
+        $($bb:ident [$i:literal] = $init:expr),+ $(,)?
+    ) => {{
+        $crate::recurrence!(@check $a $n [$($rhs)*] $($i),+);
+        // Linear exactly when every term found above is a term of `a`.
+        const LINEAR: bool =
+            true $(&& $crate::__private::same_ident(stringify!($b), stringify!($a)))+;
+        if LINEAR {
+            $crate::LinearRecurrence::from_lags(
+                $crate::__private::vec![$($init),+],
+                &[$(($k, $c)),+],
+            )
+            .as_rseq()
+        } else {
+            $crate::recurrence!(@window $a $n [$($rhs)*] $($init),+)
+        }
+    }};
+    (
+        @build $a:ident $n:ident [$($rhs:tt)*] []
+        $($bb:ident [$i:literal] = $init:expr),+ $(,)?
+    ) => {{
+        $crate::recurrence!(@check $a $n [$($rhs)*] $($i),+);
+        $crate::recurrence!(@window $a $n [$($rhs)*] $($init),+)
+    }};
+    (@window $a:ident $n:ident [$($rhs:tt)*] $($init:expr),+) => {
+        $crate::RSeq::window_recurrence(
+            $crate::__private::vec![$($init),+],
+            move |$n: usize, terms: &[_]| {
+                let $a = $crate::__private::Window::new($n, terms);
+                $($rhs)*
+            },
+        )
+        .expect("recurrence! has at least one initial term")
+    };
+    (@check $a:ident $n:ident [$($rhs:tt)*] $($i:literal),+) => {
+        const ORDER: usize = [$($i),+].len();
+        const INDICES: [usize; ORDER] = [$($i),+];
+        const _: () = {
+            let mut j = 0;
+            while j < ORDER {
+                assert!(INDICES[j] == j, "initial terms must be a[0], a[1], ... in order");
+                j += 1;
+            }
+        };
+        $crate::recurrence!(@lags $a $n $($rhs)*);
+    };
+    (@lags $a:ident $n:ident) => {};
+    (@lags $a:ident $n:ident $name:ident [$m:ident - $k:tt] $($rest:tt)*) => {
+        const _: () = {
+            let term = $crate::__private::same_ident(stringify!($name), stringify!($a));
+            assert!(
+                !term || $crate::__private::same_ident(stringify!($m), stringify!($n)),
+                "terms of a recurrence must be written a[n - k]"
+            );
+            assert!(
+                !term || (0 < $k && $k <= ORDER),
+                "a recurrence needs an initial term for every lag it uses"
+            );
+        };
+        $crate::recurrence!(@lags $a $n $($rest)*);
+    };
+    (@lags $a:ident $n:ident $name:ident [$($index:tt)*] $($rest:tt)*) => {
+        const _: () = assert!(
+            !$crate::__private::same_ident(stringify!($name), stringify!($a)),
+            "terms of a recurrence must be written a[n - k]"
+        );
+        $crate::recurrence!(@lags $a $n $($index)* $($rest)*);
+    };
+    (@lags $a:ident $n:ident ($($inner:tt)*) $($rest:tt)*) => {
+        $crate::recurrence!(@lags $a $n $($inner)* $($rest)*);
+    };
+    (@lags $a:ident $n:ident [$($inner:tt)*] $($rest:tt)*) => {
+        $crate::recurrence!(@lags $a $n $($inner)* $($rest)*);
+    };
+    (@lags $a:ident $n:ident {$($inner:tt)*} $($rest:tt)*) => {
+        $crate::recurrence!(@lags $a $n $($inner)* $($rest)*);
+    };
+    (@lags $a:ident $n:ident $t:tt $($rest:tt)*) => {
+        $crate::recurrence!(@lags $a $n $($rest)*);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn constant() {
+        assert_eq!(rseq![0; ..].take(4), vec![0; 4]);
+        assert_eq!(rseq!["a"; ..].take(2), vec!["a", "a"]);
+    }
+
+    #[test]
+    fn cycle() {
+        assert_eq!(rseq![1, 2, 3; ...].take(7), vec![1, 2, 3, 1, 2, 3, 1]);
+        assert_eq!(rseq![4; ...].take(3), vec![4, 4, 4]);
+        assert_eq!(rseq![1, 2,; ...].take(3), vec![1, 2, 1]);
+    }
+
+    #[test]
+    fn prepend() {
+        assert_eq!(
+            rseq![1, 2, 3 => RSeq::incr(10)].take(5),
+            vec![1, 2, 3, 10, 11]
+        );
+        let lazy = rseq![1, 2 => RSeq::lazy(|| panic!("rest forced"))];
+        assert_eq!(lazy.take(2), vec![1, 2]);
+        let nested = rseq![0 => rseq![1, 2; ...]];
+        assert_eq!(nested.take(5), vec![0, 1, 2, 1, 2]);
+    }
+
+    #[test]
+    fn in_expressions() {
+        let s = &rseq![1, 2; ...] + &rseq![10; ..];
+        assert_eq!(s.take(4), vec![11, 12, 11, 12]);
+        let t = &rseq![1, 2, 3 => RSeq::incr(4)] * &-&rseq![2; ..];
+        assert_eq!(t.take(5), vec![-2, -4, -6, -8, -10]);
+    }
+
+    #[test]
+    fn recurrence_fibonacci() {
+        let fibs = recurrence!(a[n] = a[n - 1] + a[n - 2]; a[0] = 0u64, a[1] = 1);
+        let expected = RSeq::unfold((0, 1), |(x, y)| (y, x + y)).map(|(x, _)| x);
+        assert!(fibs.eq_prefix(&expected, 60));
+        assert_eq!(fibs.nth(59), expected.nth(59));
+    }
+
+    #[test]
+    fn recurrence_linear() {
+        let s = recurrence!(a[n] = 2 * a[n - 1] + 3 * a[n - 3]; a[0] = 1i64, a[1] = 0, a[2] = 3);
+        let expected = RSeq::unfold_map((1i64, 0i64, 3i64), |(x, y, z)| (x, (y, z, 2 * z + 3 * x)));
+        assert!(s.eq_prefix(&expected, 30));
+        let pell = recurrence!(p[n] = 2 * p[n - 1] + p[n - 2]; p[0] = 0u64, p[1] = 1, p[2] = 2);
+        assert_eq!(pell.take(6), vec![0, 1, 2, 5, 12, 29]);
+        let halves = recurrence!(a[n] = 0.5 * a[n - 1]; a[0] = 1.0);
+        assert_eq!(halves.nth(3), 0.125);
+    }
+
+    #[test]
+    fn recurrence_same_type_either_way() {
+        // Linear, linear with a coefficient, and a window form of the same
+        // doubling recurrence are all plain sequences.
+        let sums = recurrence!(a[n] = a[n - 1] + a[n - 1]; a[0] = 1u64);
+        let scaled = recurrence!(a[n] = 2 * a[n - 1]; a[0] = 1u64);
+        let window = recurrence!(a[n] = a[n - 1] * 2; a[0] = 1u64);
+        for s in [sums, scaled] {
+            assert!(s.eq_prefix(&window, 20));
+        }
+    }
+
+    #[test]
+    fn recurrence_index_coefficient() {
+        // The coefficient is the index, not this unrelated `n`.
+        let n = 3usize;
+        let factorials = recurrence!(a[n] = n * a[n - 1]; a[0] = 1usize);
+        assert_eq!(factorials.nth(4), 24);
+        assert_eq!(n, 3);
+    }
+
+    #[test]
+    fn recurrence_other_slice() {
+        let w = [10, 20, 30, 40, 50];
+        let s = recurrence!(a[n] = a[n - 1] + w[n - 1]; a[0] = 0);
+        assert_eq!(s.take(5), vec![0, 10, 30, 60, 100]);
+    }
+
+    #[test]
+    fn recurrence_factorial() {
+        let factorials = recurrence!(f[n] = f[n - 1] * n as u64; f[0] = 1);
+        let expected = RSeq::unfold((1u64, 1u64), |(n, f)| (n + 1, f * n)).map(|(_, f)| f);
+        assert!(factorials.eq_prefix(&expected, 20));
+    }
+
+    #[test]
+    fn recurrence_order_three() {
+        let s = recurrence!(
+            a[n] = 2 * a[n - 1] - (a[n - 2] + a[n - 3]) / 2;
+            a[0] = 1i64, a[1] = 2, a[2] = 4,
+        );
+        let expected = RSeq::unfold_map((1i64, 2i64, 4i64), |(x, y, z)| {
+            (x, (y, z, 2 * z - (y + x) / 2))
+        });
+        assert!(s.eq_prefix(&expected, 30));
+    }
+
+    #[test]
+    fn recurrence_extra_initial_terms() {
+        let s = recurrence!(a[n] = a[n - 1] + 10; a[0] = 0, a[1] = 5, a[2] = 6);
+        assert_eq!(s.take(5), vec![0, 5, 6, 16, 26]);
+    }
+
+    #[test]
+    fn recurrence_other_indexing() {
+        let steps = [1, 10, 100];
+        let s = recurrence!(a[n] = a[n - 1] + steps[n % 3]; a[0] = 0);
+        assert_eq!(s.take(5), vec![0, 10, 110, 111, 121]);
+    }
+}
@@ -0,0 +1,340 @@
+use core::ops::{Add, Mul, Neg, Sub};
+
+use num_traits::{Float, Num};
+
+use crate::seq::RSeq;
+
+impl<T> RSeq<T>
+where
+    T: Add<Output = T> + Clone + 'static,
+{
+    /// The running totals `a0, a0 + a1, a0 + a1 + a2, ...`.
+    pub fn partial_sums(&self) -> Self {
+        let seq = self.clone();
+        Self::lazy(move || (seq.head().clone(), seq.tail().sums_from(seq.head().clone())))
+    }
+
+    fn sums_from(&self, total: T) -> Self {
+        let seq = self.clone();
+        Self::lazy(move || {
+            let total = total + seq.head().clone();
+            (total.clone(), seq.tail().sums_from(total))
+        })
+    }
+}
+
+impl<T> RSeq<T>
+where
+    T: Mul<Output = T> + Clone + 'static,
+{
+    /// The running products `a0, a0·a1, a0·a1·a2, ...`.
+    ///
+    /// Integer products overflow quickly; as with `*`, that panics in debug
+    /// builds.
+    pub fn cumulative_product(&self) -> Self {
+        let seq = self.clone();
+        Self::lazy(move || {
+            (
+                seq.head().clone(),
+                seq.tail().products_from(seq.head().clone()),
+            )
+        })
+    }
+
+    fn products_from(&self, product: T) -> Self {
+        let seq = self.clone();
+        Self::lazy(move || {
+            let product = product * seq.head().clone();
+            (product.clone(), seq.tail().products_from(product))
+        })
+    }
+}
+
+impl<T> RSeq<T>
+where
+    T: Sub<Output = T> + Clone + 'static,
+{
+    /// The differences between consecutive elements `a1 - a0, a2 - a1, ...`.
+    pub fn differences(&self) -> Self {
+        let seq = self.clone();
+        Self::lazy(move || {
+            let next = seq.tail();
+            let diffs = next.tail().diffs_from(next.head().clone());
+            (next.head().clone() - seq.head().clone(), diffs)
+        })
+    }
+
+    fn diffs_from(&self, prev: T) -> Self {
+        let seq = self.clone();
+        Self::lazy(move || {
+            let curr = seq.head().clone();
+            (curr.clone() - prev, seq.tail().diffs_from(curr))
+        })
+    }
+}
+
+impl<T> RSeq<T>
+where
+    T: Neg<Output = T> + Clone + 'static,
+{
+    /// Negates every odd-indexed element: `a0, -a1, a2, -a3, ...`.
+    pub fn alternate_signs(&self) -> Self {
+        self.alternate_signs_from(false)
+    }
+
+    /// Negates every other element, starting with the head if
+    /// `start_negative` is set.
+    pub fn alternate_signs_from(&self, start_negative: bool) -> Self {
+        let seq = self.clone();
+        Self::lazy(move || {
+            let head = seq.head().clone();
+            let head = if start_negative { -head } else { head };
+            (head, seq.tail().alternate_signs_from(!start_negative))
+        })
+    }
+}
+
+impl<T> RSeq<T>
+where
+    T: Num + Clone + 'static,
+{
+    /// The means of each run of `window` consecutive elements: element `n` is
+    /// the mean of elements `n..n + window`. Division is `T`'s own, so for
+    /// integers the means are truncated.
+    ///
+    /// The window sum is updated as the window slides rather than recomputed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    pub fn moving_average(&self, window: usize) -> Self {
+        assert!(window > 0, "window size must be non-zero");
+        let seq = self.clone();
+        Self::lazy(move || {
+            let len = (0..window).fold(T::zero(), |n, _| n + T::one());
+            let sum = seq
+                .suffixes()
+                .take(window)
+                .fold(T::zero(), |sum, s| sum + s.head().clone());
+            let rest = Self::averages_from(seq.clone(), seq.skip(window), sum.clone(), len.clone());
+            (sum / len, rest)
+        })
+    }
+
+    /// Continues after a window summing to `sum`, which `leaving` leaves and
+    /// `entering` enters next.
+    fn averages_from(leaving: Self, entering: Self, sum: T, len: T) -> Self {
+        Self::lazy(move || {
+            let sum = sum - leaving.head().clone() + entering.head().clone();
+            let rest =
+                Self::averages_from(leaving.tail(), entering.tail(), sum.clone(), len.clone());
+            (sum / len, rest)
+        })
+    }
+}
+
+impl<T> RSeq<T>
+where
+    T: PartialOrd + Clone + 'static,
+{
+    /// The largest element so far: element `n` is the maximum of the first
+    /// `n + 1` elements.
+    pub fn running_max(&self) -> Self {
+        self.running_by(|x, best| x > best)
+    }
+
+    /// The smallest element so far: element `n` is the minimum of the first
+    /// `n + 1` elements.
+    pub fn running_min(&self) -> Self {
+        self.running_by(|x, best| x < best)
+    }
+
+    fn running_by(&self, replaces: fn(&T, &T) -> bool) -> Self {
+        let seq = self.clone();
+        Self::lazy(move || {
+            let best = seq.head().clone();
+            (best.clone(), seq.tail().extreme_from(best, replaces))
+        })
+    }
+
+    /// Continues with `best` as the extreme so far, replacing it with any
+    /// element `x` for which `replaces(x, best)` holds.
+    fn extreme_from(&self, best: T, replaces: fn(&T, &T) -> bool) -> Self {
+        let seq = self.clone();
+        Self::lazy(move || {
+            let best = if replaces(seq.head(), &best) {
+                seq.head().clone()
+            } else {
+                best
+            };
+            (best.clone(), seq.tail().extreme_from(best, replaces))
+        })
+    }
+}
+
+impl<T> RSeq<T>
+where
+    T: Float + 'static,
+{
+    /// Aitken's Δ² transform, which speeds up the convergence of many slowly
+    /// converging sequences: `a[n] - (a[n+1] - a[n])² / (a[n+2] - 2·a[n+1] + a[n])`.
+    ///
+    /// Each element looks two elements ahead. Where the denominator is zero
+    /// the element is `a[n]` unchanged.
+    pub fn aitken(&self) -> Self {
+        self.windows::<3>().map(|[a0, a1, a2]| {
+            let denom = a2 - a1 - a1 + a0;
+            if denom.is_zero() {
+                a0
+            } else {
+                a0 - (a1 - a0).powi(2) / denom
+            }
+        })
+    }
+}
+
+impl RSeq<f64> {
+    /// The iterates of Newton's method for a root of `f`, starting at `x0`:
+    /// `x[n+1] = x[n] - f(x[n]) / df(x[n])`.
+    ///
+    /// Where the derivative is zero the next iterate is NaN, and every
+    /// iterate after it is NaN too.
+    pub fn newton(
+        f: impl Fn(f64) -> f64 + 'static,
+        df: impl Fn(f64) -> f64 + 'static,
+        x0: f64,
+    ) -> Self {
+        Self::unfold(x0, move |x| {
+            let slope = df(x);
+            if slope == 0.0 {
+                f64::NAN
+            } else {
+                x - f(x) / slope
+            }
+        })
+    }
+
+    /// Babylonian approximations to the square root of `a`,
+    /// `x[n+1] = (x[n] + a / x[n]) / 2`, starting from `max(a, 1)`.
+    ///
+    /// The iterates don't converge for negative `a`.
+    pub fn sqrt_approx(a: f64) -> Self {
+        Self::unfold(a.max(1.0), move |x| (x + a / x) / 2.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_4, PI};
+
+    use crate::prelude::*;
+
+    #[test]
+    fn partial_sums() {
+        assert_eq!(RSeq::incr(1).partial_sums().take(5), vec![1, 3, 6, 10, 15]);
+    }
+
+    #[test]
+    fn cumulative_product() {
+        assert_eq!(
+            RSeq::incr(1).cumulative_product().take(6),
+            vec![1, 2, 6, 24, 120, 720]
+        );
+        let growth = RSeq::cycle(vec![1.5, 0.5]).unwrap().cumulative_product();
+        assert_eq!(growth.take(4), vec![1.5, 0.75, 1.125, 0.5625]);
+    }
+
+    #[test]
+    fn moving_average() {
+        let s = RSeq::incr(0);
+        assert_eq!(s.moving_average(3).take(4), vec![1, 2, 3, 4]);
+        assert_eq!(s.moving_average(1).take(4), s.take(4));
+        let pattern = RSeq::cycle(vec![3, 1, 4, 1, 5]).unwrap();
+        assert_eq!(pattern.moving_average(2).take(5), vec![2, 2, 2, 3, 4]);
+        assert_eq!(pattern.moving_average(5).take(3), vec![2, 2, 2]);
+        let floats = RSeq::cycle(vec![1.0, 2.0, 4.0, 8.0]).unwrap();
+        assert_eq!(
+            floats.moving_average(3).take(4),
+            vec![7.0 / 3.0, 14.0 / 3.0, 13.0 / 3.0, 11.0 / 3.0]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn moving_average_empty_window() {
+        RSeq::incr(0).moving_average(0);
+    }
+
+    #[test]
+    fn differences() {
+        assert_eq!(RSeq::incr(5).differences().take(4), vec![1, 1, 1, 1]);
+        let squares = RSeq::incr(0).map(|n| n * n);
+        assert_eq!(squares.differences().take(5), vec![1, 3, 5, 7, 9]);
+        assert_eq!(
+            squares.differences().partial_sums().take(5),
+            (&squares.skip(1) - &RSeq::cnst(*squares.head())).take(5)
+        );
+    }
+
+    #[test]
+    fn alternate_signs() {
+        let ones = RSeq::cnst(1.0);
+        assert_eq!(ones.alternate_signs().take(4), vec![1.0, -1.0, 1.0, -1.0]);
+        assert_eq!(
+            RSeq::incr(1).alternate_signs_from(true).take(4),
+            vec![-1, 2, -3, 4]
+        );
+        let odds = RSeq::arithmetic(1.0f64, 2.0).map(|d| 1.0 / d);
+        let approx = odds.alternate_signs().partial_sums().nth(5000);
+        assert!((approx - FRAC_PI_4).abs() < 1e-3);
+    }
+
+    #[test]
+    fn leibniz() {
+        let terms = RSeq::from_fn(|n| (-1.0f64).powi(n as i32) / (2 * n + 1) as f64);
+        let approx = terms.partial_sums().nth(5000);
+        assert!((approx - FRAC_PI_4).abs() < 1e-2);
+    }
+
+    #[test]
+    fn running_extremes() {
+        let s = RSeq::cycle(vec![3, 1, 4, 1, 5]).unwrap();
+        assert_eq!(s.running_max().take(7), vec![3, 3, 4, 4, 5, 5, 5]);
+        assert_eq!(s.running_min().take(7), vec![3, 1, 1, 1, 1, 1, 1]);
+        let wave = RSeq::incr(0).map(|n| (n % 7) * (n / 7));
+        assert_eq!(
+            wave.running_max().take(10),
+            vec![0, 0, 0, 0, 0, 0, 0, 0, 1, 2]
+        );
+    }
+
+    #[test]
+    fn aitken() {
+        let terms = RSeq::from_fn(|n| (-1.0f64).powi(n as i32) / (2 * n + 1) as f64);
+        let sums = terms.partial_sums();
+        let fast = sums.aitken();
+        assert!((4.0 * fast.nth(100) - PI).abs() < 1e-6);
+        assert!((4.0 * sums.nth(100) - PI).abs() > 1e-3);
+        assert_eq!(sums.take(2), vec![1.0, 1.0 - 1.0 / 3.0]);
+        assert_eq!(RSeq::cnst(2.0).aitken().take(3), vec![2.0, 2.0, 2.0]);
+    }
+
+    #[test]
+    fn newton() {
+        let root = RSeq::newton(|x| x * x - 9.0, |x| 2.0 * x, 1.0).limit(1e-12, 100);
+        assert!((root.unwrap() - 3.0).abs() < 1e-12);
+        let flat = RSeq::newton(|x| x * x + 1.0, |x| 2.0 * x, 0.0);
+        assert!(flat.nth(1).is_nan());
+        assert!(flat.nth(5).is_nan());
+    }
+
+    #[test]
+    fn sqrt_approx() {
+        let s = RSeq::sqrt_approx(2.0);
+        let steps = s.take_while(|x| (x - 2.0f64.sqrt()).abs() >= 1e-12).len();
+        assert!(steps < 10);
+        let root = s.limit(1e-12, 10).unwrap();
+        assert!((root - 2.0f64.sqrt()).abs() < 1e-12);
+        assert!(RSeq::sqrt_approx(0.0).limit(1e-9, 100).unwrap() < 1e-8);
+    }
+}
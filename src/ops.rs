@@ -0,0 +1,247 @@
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use crate::seq::RSeq;
+
+impl<T> Add for &RSeq<T>
+where
+    T: Add<Output = T> + Clone + 'static,
+{
+    type Output = RSeq<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a + b)
+    }
+}
+
+impl<T> Mul for &RSeq<T>
+where
+    T: Mul<Output = T> + Clone + 'static,
+{
+    type Output = RSeq<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a * b)
+    }
+}
+
+impl<T> Sub for &RSeq<T>
+where
+    T: Sub<Output = T> + Clone + 'static,
+{
+    type Output = RSeq<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a - b)
+    }
+}
+
+impl<T> Div for &RSeq<T>
+where
+    T: Div<Output = T> + Clone + 'static,
+{
+    type Output = RSeq<T>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a / b)
+    }
+}
+
+impl<T> Add<T> for &RSeq<T>
+where
+    T: Add<Output = T> + Clone + 'static,
+{
+    type Output = RSeq<T>;
+
+    fn add(self, rhs: T) -> Self::Output {
+        self.map(move |a| a + rhs.clone())
+    }
+}
+
+impl<T> Mul<T> for &RSeq<T>
+where
+    T: Mul<Output = T> + Clone + 'static,
+{
+    type Output = RSeq<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map(move |a| a * rhs.clone())
+    }
+}
+
+impl<T> Rem for &RSeq<T>
+where
+    T: Rem<Output = T> + Clone + 'static,
+{
+    type Output = RSeq<T>;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a % b)
+    }
+}
+
+impl<T> RSeq<T>
+where
+    T: Rem<Output = T> + Clone + 'static,
+{
+    /// Takes every element modulo `m`.
+    ///
+    /// A zero modulus behaves as `%` does for `T`: for integers, demanding an
+    /// element panics.
+    pub fn rem_scalar(&self, m: T) -> Self {
+        self.map(move |a| a % m.clone())
+    }
+}
+
+impl<T> Neg for &RSeq<T>
+where
+    T: Neg<Output = T> + Clone + 'static,
+{
+    type Output = RSeq<T>;
+
+    fn neg(self) -> Self::Output {
+        self.map(|a| -a)
+    }
+}
+
+/// Pointwise extremes. Where two elements are incomparable, such as a NaN
+/// against anything, the element from `self` is kept.
+impl<T> RSeq<T>
+where
+    T: PartialOrd + Clone + 'static,
+{
+    /// The smaller of each pair of corresponding elements.
+    pub fn min_with(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| if b < a { b } else { a })
+    }
+
+    /// The larger of each pair of corresponding elements.
+    pub fn max_with(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| if b > a { b } else { a })
+    }
+}
+
+/// Pointwise comparisons.
+impl<T> RSeq<T>
+where
+    T: PartialOrd + Clone + 'static,
+{
+    /// Whether each element is less than the corresponding one of `other`.
+    pub fn lt(&self, other: &Self) -> RSeq<bool> {
+        self.zip_with(other, |a, b| a < b)
+    }
+
+    /// Whether each element is at most the corresponding one of `other`.
+    pub fn le(&self, other: &Self) -> RSeq<bool> {
+        self.zip_with(other, |a, b| a <= b)
+    }
+
+    /// Whether each element is greater than the corresponding one of `other`.
+    pub fn gt(&self, other: &Self) -> RSeq<bool> {
+        self.zip_with(other, |a, b| a > b)
+    }
+
+    /// Whether each element is at least the corresponding one of `other`.
+    pub fn ge(&self, other: &Self) -> RSeq<bool> {
+        self.zip_with(other, |a, b| a >= b)
+    }
+}
+
+impl<T> RSeq<T>
+where
+    T: PartialEq + Clone + 'static,
+{
+    /// Whether each element equals the corresponding one of `other`.
+    pub fn eq_seq(&self, other: &Self) -> RSeq<bool> {
+        self.zip_with(other, |a, b| a == b)
+    }
+}
+
+impl RSeq<bool> {
+    /// Picks each element from `if_true` or `if_false` according to the
+    /// corresponding condition.
+    ///
+    /// Both streams advance in lockstep with the conditions, so every step
+    /// computes the current element of each.
+    pub fn select<T>(&self, if_true: &RSeq<T>, if_false: &RSeq<T>) -> RSeq<T>
+    where
+        T: Clone + 'static,
+    {
+        self.zip(&if_true.zip(if_false))
+            .map(|(cond, (yes, no))| if cond { yes } else { no })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn ops() {
+        let s = RSeq::cnst(8);
+        let t = RSeq::cnst(2);
+        assert_eq!((&s + &t).take(5), vec![10, 10, 10, 10, 10]);
+        assert_eq!((&s - &t).take(5), vec![6, 6, 6, 6, 6]);
+        assert_eq!((&s * &t).take(5), vec![16, 16, 16, 16, 16]);
+        assert_eq!((&s / &t).take(5), vec![4, 4, 4, 4, 4]);
+    }
+
+    #[test]
+    fn scalar_ops() {
+        let s = RSeq::incr(0);
+        assert_eq!((&s * 3).take(4), vec![0, 3, 6, 9]);
+        assert_eq!((&s + 10).take(3), vec![10, 11, 12]);
+        assert_eq!((&(&s * 2) + &(&s + 1)).take(4), vec![1, 4, 7, 10]);
+    }
+
+    #[test]
+    fn rem() {
+        let s = RSeq::incr(0);
+        assert_eq!(s.rem_scalar(3).take(7), vec![0, 1, 2, 0, 1, 2, 0]);
+        assert_eq!((&s % &RSeq::cnst(4)).take(7), s.rem_scalar(4).take(7));
+    }
+
+    #[test]
+    fn neg() {
+        let a = RSeq::incr(1);
+        let b = RSeq::cnst(3);
+        assert_eq!((-&a).take(3), vec![-1, -2, -3]);
+        assert_eq!((&a + &(-&b)).take(5), (&a - &b).take(5));
+    }
+
+    #[test]
+    fn min_max_with() {
+        let s = RSeq::incr(0);
+        let cap = RSeq::cnst(3);
+        assert_eq!(s.min_with(&cap).take(6), vec![0, 1, 2, 3, 3, 3]);
+        assert_eq!(cap.min_with(&s).take(6), vec![0, 1, 2, 3, 3, 3]);
+        assert_eq!(s.max_with(&cap).take(6), vec![3, 3, 3, 3, 4, 5]);
+        assert_eq!(cap.max_with(&s).take(6), vec![3, 3, 3, 3, 4, 5]);
+        let nan = RSeq::cnst(f64::NAN);
+        assert!(nan.min_with(&RSeq::cnst(1.0)).head().is_nan());
+        assert_eq!(*RSeq::cnst(1.0).max_with(&nan).head(), 1.0);
+    }
+
+    #[test]
+    fn comparisons() {
+        let s = RSeq::incr(0);
+        let three = RSeq::cnst(3);
+        assert_eq!(s.lt(&three).take(5), vec![true, true, true, false, false]);
+        assert_eq!(s.le(&three).take(5), vec![true, true, true, true, false]);
+        assert_eq!(s.gt(&three).take(5), vec![false, false, false, false, true]);
+        assert_eq!(s.ge(&three).take(5), vec![false, false, false, true, true]);
+        assert_eq!(
+            s.eq_seq(&three).take(5),
+            vec![false, false, false, true, false]
+        );
+    }
+
+    #[test]
+    fn select() {
+        let cond = RSeq::incr(0).lt(&RSeq::cnst(3));
+        let ints = cond.select(&RSeq::cnst(1), &RSeq::cnst(0));
+        assert_eq!(ints.take(6), cond.map(i32::from).take(6));
+        let s = RSeq::incr(0);
+        let piecewise = cond.select(&(&s * 10), &-&s);
+        assert_eq!(piecewise.take(5), vec![0, 10, 20, -3, -4]);
+    }
+}
@@ -0,0 +1,135 @@
+use num_traits::{
+    CheckedAdd, CheckedMul, CheckedSub, SaturatingAdd, SaturatingMul, WrappingAdd, WrappingMul,
+    Zero,
+};
+
+use crate::seq::RSeq;
+
+/// Pointwise arithmetic that reports overflow as `None` instead of panicking
+/// or wrapping.
+impl<T> RSeq<T>
+where
+    T: Clone + 'static,
+{
+    /// The sums of corresponding elements, or `None` where a sum overflows.
+    pub fn checked_add_seq(&self, other: &Self) -> RSeq<Option<T>>
+    where
+        T: CheckedAdd,
+    {
+        self.zip_with(other, |a, b| a.checked_add(&b))
+    }
+
+    /// The differences of corresponding elements, or `None` where a
+    /// difference overflows.
+    pub fn checked_sub_seq(&self, other: &Self) -> RSeq<Option<T>>
+    where
+        T: CheckedSub,
+    {
+        self.zip_with(other, |a, b| a.checked_sub(&b))
+    }
+
+    /// The products of corresponding elements, or `None` where a product
+    /// overflows.
+    pub fn checked_mul_seq(&self, other: &Self) -> RSeq<Option<T>>
+    where
+        T: CheckedMul,
+    {
+        self.zip_with(other, |a, b| a.checked_mul(&b))
+    }
+
+    /// The running totals, or `None` from the first total that overflows on.
+    pub fn checked_partial_sums(&self) -> RSeq<Option<T>>
+    where
+        T: CheckedAdd + Zero,
+    {
+        self.scan(Some(T::zero()), |total: Option<T>, x| {
+            let total = total.and_then(|t| t.checked_add(&x));
+            (total.clone(), total)
+        })
+    }
+}
+
+/// Pointwise arithmetic that wraps or saturates on overflow, behaving the
+/// same in debug and release builds.
+impl<T> RSeq<T>
+where
+    T: Clone + 'static,
+{
+    /// The sums of corresponding elements, wrapping around on overflow.
+    pub fn wrapping_add_seq(&self, other: &Self) -> Self
+    where
+        T: WrappingAdd,
+    {
+        self.zip_with(other, |a, b| a.wrapping_add(&b))
+    }
+
+    /// The products of corresponding elements, wrapping around on overflow.
+    pub fn wrapping_mul_seq(&self, other: &Self) -> Self
+    where
+        T: WrappingMul,
+    {
+        self.zip_with(other, |a, b| a.wrapping_mul(&b))
+    }
+
+    /// The sums of corresponding elements, clamped to `T`'s bounds.
+    pub fn saturating_add_seq(&self, other: &Self) -> Self
+    where
+        T: SaturatingAdd,
+    {
+        self.zip_with(other, |a, b| a.saturating_add(&b))
+    }
+
+    /// The products of corresponding elements, clamped to `T`'s bounds.
+    pub fn saturating_mul_seq(&self, other: &Self) -> Self
+    where
+        T: SaturatingMul,
+    {
+        self.zip_with(other, |a, b| a.saturating_mul(&b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn checked_ops() {
+        let s = RSeq::incr(250u8);
+        let sums = s.checked_add_seq(&RSeq::cnst(3));
+        assert_eq!(sums.take(4), vec![Some(253), Some(254), Some(255), None]);
+        assert_eq!(sums.position_within(10, Option::is_none), Some(3));
+        let diffs = RSeq::incr(0u8).checked_sub_seq(&RSeq::cnst(1));
+        assert_eq!(diffs.take(2), vec![None, Some(0)]);
+        let squares = RSeq::incr(14u8).checked_mul_seq(&RSeq::incr(14));
+        assert_eq!(squares.take(3), vec![Some(196), Some(225), None]);
+    }
+
+    #[test]
+    fn checked_partial_sums() {
+        let sums = RSeq::incr(1u8).checked_partial_sums();
+        assert_eq!(sums.position_within(100, Option::is_none), Some(22));
+        assert_eq!(sums.nth(21), Some(253));
+        assert!(sums.skip(22).all_n(30, Option::is_none));
+    }
+
+    #[test]
+    fn wrapping_ops() {
+        let counter = RSeq::cnst(250u8).wrapping_add_seq(&RSeq::incr(0));
+        assert_eq!(
+            counter.take(10),
+            vec![250, 251, 252, 253, 254, 255, 0, 1, 2, 3]
+        );
+        let doubled = RSeq::incr(126u8).wrapping_mul_seq(&RSeq::cnst(2));
+        assert_eq!(doubled.take(4), vec![252, 254, 0, 2]);
+    }
+
+    #[test]
+    fn saturating_ops() {
+        let pinned = RSeq::cnst(250u8).saturating_add_seq(&RSeq::incr(0));
+        assert_eq!(pinned.take(8), vec![250, 251, 252, 253, 254, 255, 255, 255]);
+        let doubled = RSeq::incr(126u8).saturating_mul_seq(&RSeq::cnst(2));
+        assert_eq!(doubled.take(4), vec![252, 254, 255, 255]);
+        let low = RSeq::cnst(-120i8).saturating_add_seq(&RSeq::incr(0).map(|n: i8| -n * 5));
+        assert_eq!(low.take(4), vec![-120, -125, -128, -128]);
+    }
+}
@@ -0,0 +1,77 @@
+use rayon::prelude::*;
+
+use crate::{seq::RSeq, sync::ARSeq};
+
+impl<T> RSeq<T>
+where
+    T: Clone + Send + 'static,
+{
+    /// Applies `f` to each of the first `n` elements, in parallel, keeping
+    /// their order.
+    ///
+    /// The elements themselves are computed first, one after another; only
+    /// the calls to `f` run in parallel.
+    pub fn par_take_map<U>(&self, n: usize, f: impl Fn(T) -> U + Sync + Send) -> Vec<U>
+    where
+        U: Send,
+    {
+        self.take(n).into_par_iter().map(f).collect()
+    }
+}
+
+impl<T> ARSeq<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    /// Collects the first `n` elements into a `Vec`.
+    ///
+    /// For sequences built by [`ARSeq::from_fn`], and their tails, every
+    /// element is computed independently and in parallel; the results are
+    /// not cached in the sequence. Any other sequence is walked one element
+    /// after another, as by [`ARSeq::take`].
+    pub fn par_take(&self, n: usize) -> Vec<T> {
+        match &self.index {
+            Some((start, f)) => (*start..*start + n).into_par_iter().map(|i| f(i)).collect(),
+            None => self.take(n),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use rayon::ThreadPoolBuilder;
+
+    use crate::prelude::*;
+
+    /// Gives the tests enough worker threads to overlap their sleeps, however
+    /// many cores the machine has.
+    fn init_pool() {
+        let _ = ThreadPoolBuilder::new().num_threads(8).build_global();
+    }
+
+    fn slow_square(n: u64) -> u64 {
+        thread::sleep(Duration::from_millis(5));
+        n * n
+    }
+
+    #[test]
+    fn par_take_map() {
+        init_pool();
+        let s = RSeq::incr(0u64).filter(|n| n % 2 == 0);
+        let serial: Vec<_> = s.take(64).into_iter().map(slow_square).collect();
+        assert_eq!(s.par_take_map(64, slow_square), serial);
+    }
+
+    #[test]
+    fn par_take() {
+        init_pool();
+        let squares = ARSeq::from_fn(|i| slow_square(i as u64));
+        let expected = RSeq::from_fn(|i| (i * i) as u64);
+        assert_eq!(squares.par_take(64), expected.take(64));
+        assert_eq!(squares.tail().tail().par_take(3), vec![4, 9, 16]);
+        let evens = ARSeq::incr(0u64).filter(|n| n % 2 == 0);
+        assert_eq!(evens.par_take(4), vec![0, 2, 4, 6]);
+    }
+}
@@ -0,0 +1,688 @@
+use core::{
+    cmp::Ordering,
+    ops::{ControlFlow, Sub},
+};
+
+use num_traits::{Float, Num};
+
+use crate::seq::RSeq;
+#[cfg(all(feature = "std", panic = "unwind"))]
+use crate::thunk;
+
+/// Queries over a bounded prefix of a sequence. None of them force any
+/// element past the last one they need to inspect.
+impl<T> RSeq<T>
+where
+    T: PartialEq,
+{
+    /// Whether the sequence begins with `prefix`.
+    pub fn starts_with(&self, prefix: &[T]) -> bool {
+        prefix
+            .iter()
+            .zip(self.suffixes())
+            .all(|(x, seq)| seq.head() == x)
+    }
+
+    /// Whether the first `n` elements of `self` and `other` are equal.
+    pub fn eq_prefix(&self, other: &Self, n: usize) -> bool {
+        self.suffixes()
+            .zip(other.suffixes())
+            .take(n)
+            .all(|(a, b)| a.head() == b.head())
+    }
+
+    /// The index of the first occurrence of `pattern` that starts among the
+    /// first `limit` elements. An occurrence may run past them, so up to
+    /// `limit + pattern.len() - 1` elements are inspected.
+    ///
+    /// An empty pattern occurs at index 0.
+    pub fn find_subsequence(&self, pattern: &[T], limit: usize) -> Option<usize> {
+        if pattern.is_empty() {
+            return Some(0);
+        }
+        self.suffixes()
+            .take(limit)
+            .position(|seq| seq.starts_with(pattern))
+    }
+}
+
+impl<T> RSeq<T>
+where
+    T: Ord,
+{
+    /// Compares the first `n` elements of `self` and `other`
+    /// lexicographically, stopping at the first pair that differs.
+    pub fn cmp_prefix(&self, other: &Self, n: usize) -> Ordering {
+        self.suffixes()
+            .zip(other.suffixes())
+            .take(n)
+            .map(|(a, b)| a.head().cmp(b.head()))
+            .find(|ord| ord.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl<T> RSeq<T>
+where
+    T: PartialOrd,
+{
+    /// Compares the first `n` elements of `self` and `other`
+    /// lexicographically, stopping at the first pair that differs.
+    ///
+    /// Returns `None` as soon as a pair is incomparable, such as a NaN.
+    pub fn partial_cmp_prefix(&self, other: &Self, n: usize) -> Option<Ordering> {
+        for (a, b) in self.suffixes().zip(other.suffixes()).take(n) {
+            match a.head().partial_cmp(b.head())? {
+                Ordering::Equal => {}
+                ord => return Some(ord),
+            }
+        }
+        Some(Ordering::Equal)
+    }
+}
+
+impl<T> RSeq<T>
+where
+    T: PartialOrd,
+{
+    /// Whether the first `n` elements are strictly increasing.
+    pub fn is_increasing_n(&self, n: usize) -> bool {
+        self.consecutive_n(n, |a, b| a < b)
+    }
+
+    /// Whether the first `n` elements never decrease.
+    pub fn is_non_decreasing_n(&self, n: usize) -> bool {
+        self.consecutive_n(n, |a, b| a <= b)
+    }
+
+    /// Whether the first `n` elements are strictly decreasing.
+    pub fn is_decreasing_n(&self, n: usize) -> bool {
+        self.consecutive_n(n, |a, b| a > b)
+    }
+
+    /// Whether the first `n` elements never increase.
+    pub fn is_non_increasing_n(&self, n: usize) -> bool {
+        self.consecutive_n(n, |a, b| a >= b)
+    }
+
+    /// Whether `ordered` holds for each pair of consecutive elements among
+    /// the first `n`, stopping at the first pair where it fails. Comparisons
+    /// involving NaN fail.
+    fn consecutive_n(&self, n: usize, ordered: fn(&T, &T) -> bool) -> bool {
+        self.suffixes()
+            .zip(self.suffixes().skip(1))
+            .take(n.saturating_sub(1))
+            .all(|(a, b)| ordered(a.head(), b.head()))
+    }
+}
+
+impl<T> RSeq<T> {
+    /// The index of the first element satisfying `pred` among the first
+    /// `limit` elements.
+    pub fn position_within(&self, limit: usize, pred: impl Fn(&T) -> bool) -> Option<usize> {
+        self.suffixes().take(limit).position(|seq| pred(seq.head()))
+    }
+
+    /// Whether all of the first `n` elements satisfy `pred`. Stops at the
+    /// first element that fails.
+    pub fn all_n(&self, n: usize, pred: impl Fn(&T) -> bool) -> bool {
+        self.suffixes().take(n).all(|seq| pred(seq.head()))
+    }
+
+    /// Whether any of the first `n` elements satisfies `pred`. Stops at the
+    /// first element that passes.
+    pub fn any_n(&self, n: usize, pred: impl Fn(&T) -> bool) -> bool {
+        self.suffixes().take(n).any(|seq| pred(seq.head()))
+    }
+}
+
+impl<T> RSeq<T>
+where
+    T: Clone + 'static,
+{
+    /// The first element satisfying `pred` among the first `limit` elements.
+    pub fn find_within(&self, limit: usize, pred: impl Fn(&T) -> bool) -> Option<T> {
+        self.suffixes()
+            .take(limit)
+            .find(|seq| pred(seq.head()))
+            .map(|seq| seq.head().clone())
+    }
+
+    /// Collects the first `n` elements, or returns `None` if that would
+    /// compute more than `fuel` elements, counting those of every sequence
+    /// the computation draws on. Elements that are already cached are free.
+    ///
+    /// When the fuel runs out, elements whose computation was interrupted are
+    /// left as they were before, so they can still be demanded later. Only
+    /// available when panics unwind.
+    ///
+    /// Side effects a closure made before being interrupted are not undone,
+    /// so for sequences drawing on outside state, such as those from
+    /// [`IntoRSeq`](crate::IntoRSeq) or [`RSeq::map_mut`], later demands may
+    /// skip or repeat the values produced during the interrupted call.
+    #[cfg(all(feature = "std", panic = "unwind"))]
+    pub fn try_take(&self, n: usize, fuel: usize) -> Option<Vec<T>> {
+        thunk::with_fuel(fuel, || self.take(n))
+    }
+
+    /// Folds the first `n` elements into an accumulator without collecting
+    /// them.
+    pub fn fold_n<A>(&self, n: usize, init: A, f: impl FnMut(A, T) -> A) -> A {
+        self.take_iter(n).fold(init, f)
+    }
+
+    /// Folds the first `n` elements, stopping at the first error `f`
+    /// returns. No element after the failing one is forced.
+    pub fn try_fold_n<A, E>(
+        &self,
+        n: usize,
+        init: A,
+        f: impl FnMut(A, T) -> Result<A, E>,
+    ) -> Result<A, E> {
+        self.take_iter(n).try_fold(init, f)
+    }
+
+    /// Folds elements until `f` breaks, returning the accumulator it breaks
+    /// with. No element after that one is forced.
+    ///
+    /// Loops forever if `f` never breaks.
+    pub fn fold_while<A>(&self, init: A, f: impl FnMut(A, T) -> ControlFlow<A, A>) -> A {
+        match self.iter().try_fold(init, f) {
+            ControlFlow::Break(acc) | ControlFlow::Continue(acc) => acc,
+        }
+    }
+}
+
+/// Where a sequence becomes periodic, as found by [`RSeq::detect_cycle`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CycleInfo {
+    /// The index of the first element of the first full period.
+    pub start: usize,
+    /// The length of the period.
+    pub period: usize,
+}
+
+impl<T> RSeq<T>
+where
+    T: PartialEq + Clone + 'static,
+{
+    /// Finds where the sequence starts repeating, using Brent's algorithm.
+    /// At most `limit` elements are forced, and since they stay cached in
+    /// the sequence, memory use is linear in `limit`.
+    ///
+    /// The sequence is taken to repeat from the first time an element equals
+    /// an earlier one, which holds when each element determines the next, as
+    /// for [`RSeq::unfold`]. Returns `None` if no repeat shows up within the
+    /// first `limit` elements.
+    pub fn detect_cycle(&self, limit: usize) -> Option<CycleInfo> {
+        let mut values = self.take_iter(limit);
+        let mut tortoise = values.next()?;
+        let mut hare = values.next()?;
+        let (mut power, mut period) = (1, 1);
+        while tortoise != hare {
+            if power == period {
+                tortoise = hare.clone();
+                power *= 2;
+                period = 0;
+            }
+            hare = values.next()?;
+            period += 1;
+        }
+        // Both elements of every pair compared here were forced above.
+        let start = self
+            .iter()
+            .zip(self.skip(period).iter())
+            .position(|(a, b)| a == b)?;
+        Some(CycleInfo { start, period })
+    }
+}
+
+impl<T> RSeq<T>
+where
+    T: Num + Clone + 'static,
+{
+    /// The sum of the first `n` elements.
+    pub fn sum_n(&self, n: usize) -> T {
+        self.fold_n(n, T::zero(), |acc, x| acc + x)
+    }
+
+    /// The product of the first `n` elements.
+    pub fn product_n(&self, n: usize) -> T {
+        self.fold_n(n, T::one(), |acc, x| acc * x)
+    }
+}
+
+/// Summary statistics of a prefix, as computed by [`RSeq::stats_n`].
+///
+/// For an empty prefix every field but `count` is NaN.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PrefixStats {
+    /// The number of elements.
+    pub count: usize,
+    /// The arithmetic mean.
+    pub mean: f64,
+    /// The population variance, dividing by `count`.
+    pub variance: f64,
+    /// The population standard deviation.
+    pub stddev: f64,
+    /// The smallest element.
+    pub min: f64,
+    /// The largest element.
+    pub max: f64,
+}
+
+impl<T> RSeq<T>
+where
+    T: Into<f64> + Clone + 'static,
+{
+    /// The count, mean, variance, standard deviation, minimum and maximum of
+    /// the first `n` elements, computed in one pass without storing them.
+    ///
+    /// The mean and variance are updated with Welford's algorithm, which
+    /// stays accurate when the elements are large but close together.
+    pub fn stats_n(&self, n: usize) -> PrefixStats {
+        let mut stats = PrefixStats {
+            count: 0,
+            mean: f64::NAN,
+            variance: f64::NAN,
+            stddev: f64::NAN,
+            min: f64::NAN,
+            max: f64::NAN,
+        };
+        let (mut mean, mut squares) = (0.0, 0.0);
+        for x in self.take_iter(n).map(Into::into) {
+            stats.count += 1;
+            let delta = x - mean;
+            mean += delta / stats.count as f64;
+            squares += delta * (x - mean);
+            stats.min = if stats.count == 1 {
+                x
+            } else {
+                stats.min.min(x)
+            };
+            stats.max = if stats.count == 1 {
+                x
+            } else {
+                stats.max.max(x)
+            };
+        }
+        if stats.count > 0 {
+            stats.mean = mean;
+            stats.variance = squares / stats.count as f64;
+            stats.stddev = Float::sqrt(stats.variance);
+        }
+        stats
+    }
+}
+
+impl<T> RSeq<T>
+where
+    T: Sub<Output = T> + PartialOrd + Clone + 'static,
+{
+    /// The first element that differs from its predecessor by less than
+    /// `tolerance`, comparing at most `max_steps` consecutive pairs.
+    ///
+    /// Returns `None` if no pair within `max_steps` is close enough.
+    pub fn limit(&self, tolerance: T, max_steps: usize) -> Option<T> {
+        let mut prev = self.head().clone();
+        for seq in self.suffixes().skip(1).take(max_steps) {
+            let curr = seq.head().clone();
+            let gap = if curr > prev {
+                curr.clone() - prev
+            } else {
+                prev - curr.clone()
+            };
+            if gap < tolerance {
+                return Some(curr);
+            }
+            prev = curr;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, cmp::Ordering, ops::ControlFlow};
+
+    use crate::{prelude::*, CycleInfo};
+
+    fn diverging() -> RSeq<i32> {
+        RSeq::lazy(|| {
+            (
+                1,
+                RSeq::lazy(|| (2, RSeq::lazy(|| panic!("forced too far")))),
+            )
+        })
+    }
+
+    #[test]
+    fn starts_with() {
+        assert!(RSeq::incr(0).starts_with(&[0, 1, 2]));
+        assert!(!RSeq::incr(0).starts_with(&[0, 2]));
+        let evens = RSeq::incr(0).filter(|n| n % 2 == 0);
+        assert!(evens.starts_with(&[0, 2, 4, 6]));
+        assert!(diverging().starts_with(&[1, 2]));
+        assert!(!diverging().starts_with(&[0, 5, 6, 7]));
+        assert!(RSeq::<i32>::lazy(|| panic!("head forced")).starts_with(&[]));
+    }
+
+    #[test]
+    fn eq_prefix() {
+        let s = RSeq::incr(0);
+        let t = RSeq::incr(0).filter(|n| n % 2 == 0).map(|n| n / 2);
+        assert!(s.eq_prefix(&t, 10));
+        assert!(!s.eq_prefix(&RSeq::cnst(0), 2));
+        assert!(diverging().eq_prefix(&RSeq::incr(1), 2));
+        assert!(!diverging().eq_prefix(&RSeq::cnst(0), 3));
+    }
+
+    #[test]
+    fn find_subsequence() {
+        let sevenths = RSeq::decimal_expansion(1, 7).unwrap();
+        assert_eq!(sevenths.find_subsequence(&[8, 5, 7, 1], 100), Some(3));
+        assert_eq!(sevenths.find_subsequence(&[1, 4, 2], 100), Some(0));
+        assert_eq!(sevenths.find_subsequence(&[1, 1], 100), None);
+        let digits = RSeq::decimal_expansion(141, 999).unwrap();
+        assert_eq!(digits.find_subsequence(&[1, 4, 1], 100), Some(0));
+        assert_eq!(digits.find_subsequence(&[4, 1, 1], 100), Some(1));
+        let s = RSeq::cycle(vec!['a', 'b', 'c']).unwrap();
+        assert_eq!(s.find_subsequence(&['c', 'a', 'b'], 10), Some(2));
+        assert_eq!(s.find_subsequence(&['c', 'a', 'b'], 2), None);
+        assert_eq!(s.find_subsequence(&['c', 'a', 'b'], 3), Some(2));
+        assert_eq!(s.find_subsequence(&[], 0), Some(0));
+    }
+
+    #[test]
+    fn find_subsequence_within_limit() {
+        let (s, counter) = RSeq::incr(0).counted();
+        assert_eq!(s.find_subsequence(&[9, 11], 10), None);
+        assert_eq!(counter.count(), 11);
+        assert_eq!(diverging().find_subsequence(&[2, 3], 1), None);
+        assert_eq!(diverging().find_subsequence(&[1, 2], 1), Some(0));
+    }
+
+    #[test]
+    fn cmp_prefix() {
+        let s = RSeq::incr(0);
+        assert_eq!(s.cmp_prefix(&RSeq::incr(1), 5), Ordering::Less);
+        assert_eq!(RSeq::incr(1).cmp_prefix(&s, 5), Ordering::Greater);
+        assert_eq!(s.cmp_prefix(&s.map(|n| n), 100), Ordering::Equal);
+        assert_eq!(s.cmp_prefix(&RSeq::cnst(0), 0), Ordering::Equal);
+        assert_eq!(diverging().cmp_prefix(&RSeq::incr(1), 2), Ordering::Equal);
+        let differs = RSeq::cons(1, RSeq::cons(5, RSeq::cnst(0)));
+        assert_eq!(diverging().cmp_prefix(&differs, 10), Ordering::Less);
+        assert_eq!(differs.cmp_prefix(&diverging(), 10), Ordering::Greater);
+    }
+
+    #[test]
+    fn partial_cmp_prefix() {
+        let halves = RSeq::geometric(1.0, 0.5);
+        let thirds = RSeq::geometric(1.0, 1.0 / 3.0);
+        assert_eq!(
+            halves.partial_cmp_prefix(&thirds, 5),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(halves.partial_cmp_prefix(&halves, 5), Some(Ordering::Equal));
+        let nan = RSeq::cons(1.0, RSeq::cnst(f64::NAN));
+        assert_eq!(halves.partial_cmp_prefix(&nan, 5), None);
+        assert_eq!(halves.partial_cmp_prefix(&nan, 1), Some(Ordering::Equal));
+        let stops = RSeq::cons(0.0, RSeq::lazy(|| panic!("forced too far")));
+        assert_eq!(stops.partial_cmp_prefix(&nan, 5), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn monotonic() {
+        let s = RSeq::incr(0);
+        assert!(s.is_increasing_n(1000));
+        assert!(s.is_non_decreasing_n(1000));
+        assert!(!s.is_decreasing_n(2));
+        assert!(RSeq::incr(0).map(|n: i32| -n).is_decreasing_n(1000));
+        let steps = RSeq::incr(0).map(|n| n / 2);
+        assert!(steps.is_non_decreasing_n(100));
+        assert!(!steps.is_increasing_n(100));
+        assert!(RSeq::cnst(3).is_non_increasing_n(100));
+        assert!(!RSeq::cnst(3).is_decreasing_n(100));
+        let untouched = RSeq::<i32>::lazy(|| panic!("head forced"));
+        assert!(untouched.is_increasing_n(0));
+        assert!(untouched.is_decreasing_n(1));
+    }
+
+    #[test]
+    fn monotonic_stops_at_violation() {
+        let (s, counter) = RSeq::cycle(vec![1, 2, 3]).unwrap().counted();
+        assert!(s.is_increasing_n(3));
+        assert!(!s.is_increasing_n(100));
+        assert_eq!(counter.count(), 4);
+        assert!(!diverging().is_decreasing_n(100));
+        assert!(diverging().is_increasing_n(2));
+    }
+
+    #[test]
+    fn monotonic_nan() {
+        let s = RSeq::incr(0.0).prepend(vec![f64::NAN]);
+        assert!(!s.is_increasing_n(10));
+        assert!(!s.is_non_decreasing_n(10));
+        assert!(!s.is_decreasing_n(10));
+        assert!(!s.is_non_increasing_n(10));
+        assert!(s.tail().is_increasing_n(10));
+    }
+
+    #[test]
+    fn find_within() {
+        let s = RSeq::incr(0);
+        assert_eq!(s.find_within(10, |&n| n * n > 50), Some(8));
+        assert_eq!(s.find_within(8, |&n| n * n > 50), None);
+        assert_eq!(s.find_within(0, |_| true), None);
+        assert_eq!(diverging().find_within(2, |&n| n > 5), None);
+    }
+
+    #[test]
+    fn position_within() {
+        let s = RSeq::incr(1);
+        assert_eq!(s.position_within(100, |n| n % 7 == 0), Some(6));
+        assert_eq!(s.nth(6), 7);
+        assert_eq!(s.position_within(1000, |&n| n < 0), None);
+        assert_eq!(diverging().position_within(2, |&n| n == 2), Some(1));
+        let untouched = RSeq::lazy(|| panic!("head forced"));
+        assert_eq!(untouched.position_within(0, |_: &i32| true), None);
+    }
+
+    #[test]
+    fn all_any_n() {
+        let evens = RSeq::incr(0).filter(|n| n % 2 == 0);
+        assert!(evens.all_n(1000, |n| n % 2 == 0));
+        assert!(!evens.all_n(1000, |&n| n < 100));
+        assert!(!diverging().all_n(5, |&n| n < 2));
+        let calls = Cell::new(0);
+        let counted = |&n: &i32| {
+            calls.set(calls.get() + 1);
+            n == 0
+        };
+        assert!(RSeq::incr(0).any_n(1000, counted));
+        assert_eq!(calls.get(), 1);
+        assert!(diverging().any_n(5, |&n| n == 2));
+        let untouched = RSeq::<i32>::lazy(|| panic!("head forced"));
+        assert!(untouched.all_n(0, |_| false));
+        assert!(!untouched.any_n(0, |_| true));
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", panic = "unwind"))]
+    fn try_take() {
+        let s = RSeq::incr(0);
+        assert_eq!(s.try_take(5, 5), Some(vec![0, 1, 2, 3, 4]));
+        assert_eq!(RSeq::incr(0).try_take(5, 4), None);
+        let evens = RSeq::incr(0).filter(|n| n % 2 == 0);
+        assert_eq!(evens.try_take(3, 100), Some(vec![0, 2, 4]));
+        assert_eq!(evens.try_take(3, 0), Some(vec![0, 2, 4]));
+        let none = RSeq::incr(0).filter(|n| n < 0);
+        assert_eq!(none.try_take(1, 10_000), None);
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", panic = "unwind"))]
+    fn try_take_interrupted() {
+        let squares = RSeq::incr(0u64).map(|n| n * n);
+        let big = squares.filter(|n| n > 10_000);
+        assert_eq!(big.try_take(1, 50), None);
+        assert_eq!(big.take(2), vec![10_201, 10_404]);
+        assert_eq!(squares.nth(5), 25);
+        let s = RSeq::incr(0);
+        assert_eq!(s.try_take(10, 3), None);
+        assert_eq!(s.try_take(10, 10), Some((0..10).collect()));
+        let pairs = RSeq::incr(0).product_diagonal(&RSeq::incr(0));
+        assert_eq!(pairs.try_take(10, 12), None);
+        assert_eq!(pairs.nth(5), (2, 0));
+        let squares = RSeq::incr(1u64).series_mul(&RSeq::incr(1));
+        assert_eq!(squares.try_take(5, 8), None);
+        assert_eq!(squares.take(4), vec![1, 4, 10, 20]);
+    }
+
+    #[test]
+    fn fold_n() {
+        assert_eq!(RSeq::incr(1).sum_n(100), 5050);
+        assert_eq!(RSeq::incr(1).product_n(5), 120);
+        assert_eq!(RSeq::incr(1).product_n(0), 1);
+        let digits = RSeq::cycle("314159".chars().collect()).unwrap();
+        let text = digits.fold_n(8, String::new(), |mut acc, c| {
+            acc.push(c);
+            acc
+        });
+        assert_eq!(text, "31415931");
+        let untouched = RSeq::lazy(|| panic!("head forced"));
+        assert_eq!(untouched.fold_n(0, 7, |acc, x: i32| acc + x), 7);
+        assert_eq!(diverging().sum_n(2), 3);
+    }
+
+    #[test]
+    fn fold_while() {
+        let total = RSeq::incr(1).fold_while(0, |acc, n| {
+            let acc = acc + n;
+            if acc > 100 {
+                ControlFlow::Break(acc)
+            } else {
+                ControlFlow::Continue(acc)
+            }
+        });
+        assert_eq!(total, 105);
+        let count = RSeq::cnst(1u64).fold_while(0u64, |acc, n| {
+            if acc == 2_000_000 {
+                ControlFlow::Break(acc)
+            } else {
+                ControlFlow::Continue(acc + n)
+            }
+        });
+        assert_eq!(count, 2_000_000);
+    }
+
+    #[test]
+    fn try_fold_n() {
+        let signs = RSeq::incr(1).alternate_signs();
+        let checked = |acc: i32, n: i32| if n < 0 { Err(n) } else { Ok(acc + n) };
+        assert_eq!(signs.try_fold_n(5, 0, checked), Err(-2));
+        assert_eq!(signs.try_fold_n(1, 0, checked), Ok(1));
+        assert_eq!(
+            diverging().try_fold_n(5, 0, |_, n| Err::<i32, _>(n)),
+            Err(1)
+        );
+    }
+
+    #[test]
+    fn detect_cycle() {
+        let cycle = CycleInfo {
+            start: 0,
+            period: 3,
+        };
+        let s = RSeq::cycle(vec![1, 2, 3]).unwrap();
+        assert_eq!(s.detect_cycle(100), Some(cycle));
+        assert_eq!(s.detect_cycle(3), None);
+        let lcg = RSeq::unfold(1, |x| (x * 5 + 3) % 16);
+        assert_eq!(
+            lcg.detect_cycle(100),
+            Some(CycleInfo {
+                start: 0,
+                period: 16
+            })
+        );
+        assert_eq!(
+            lcg.prepend(vec![20, 30]).detect_cycle(100),
+            Some(CycleInfo {
+                start: 2,
+                period: 16
+            })
+        );
+        let squares = RSeq::unfold(3, |x| x * x % 100);
+        assert_eq!(squares.take(7), vec![3, 9, 81, 61, 21, 41, 81]);
+        assert_eq!(
+            squares.detect_cycle(100),
+            Some(CycleInfo {
+                start: 2,
+                period: 4
+            })
+        );
+        assert_eq!(
+            RSeq::cnst(7).detect_cycle(2),
+            Some(CycleInfo {
+                start: 0,
+                period: 1
+            })
+        );
+        assert_eq!(RSeq::cnst(7).detect_cycle(1), None);
+    }
+
+    #[test]
+    fn detect_cycle_within_limit() {
+        let (s, counter) = RSeq::incr(0).counted();
+        assert_eq!(s.detect_cycle(1000), None);
+        assert_eq!(counter.count(), 1000);
+        assert_eq!(diverging().detect_cycle(2), None);
+    }
+
+    #[test]
+    fn stats_n() {
+        let stats = RSeq::incr(1).stats_n(100);
+        assert_eq!(stats.count, 100);
+        assert_eq!(stats.mean, 50.5);
+        assert!((stats.variance - (100.0 * 100.0 - 1.0) / 12.0).abs() < 1e-9);
+        assert!((stats.stddev - stats.variance.sqrt()).abs() < 1e-12);
+        assert_eq!((stats.min, stats.max), (1.0, 100.0));
+        let constant = RSeq::cnst(5.0).stats_n(50);
+        assert_eq!((constant.mean, constant.variance), (5.0, 0.0));
+        assert_eq!((constant.min, constant.max), (5.0, 5.0));
+        assert_eq!(diverging().stats_n(2).max, 2.0);
+    }
+
+    #[test]
+    fn stats_n_empty() {
+        let untouched = RSeq::<f64>::lazy(|| panic!("head forced"));
+        let stats = untouched.stats_n(0);
+        assert_eq!(stats.count, 0);
+        assert!(stats.mean.is_nan() && stats.variance.is_nan());
+        assert!(stats.min.is_nan() && stats.max.is_nan());
+    }
+
+    #[test]
+    fn stats_n_stable() {
+        let big = &RSeq::cycle(vec![4.0, 7.0, 13.0, 16.0]).unwrap() + 1e9;
+        let stats = big.stats_n(40_000);
+        assert!((stats.mean - (1e9 + 10.0)).abs() < 1e-5);
+        assert!((stats.variance - 22.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn limit() {
+        let halves = RSeq::geometric(1.0, 0.5);
+        let zero = halves.limit(1e-9, 100).unwrap();
+        assert!(zero < 1e-9);
+        let basel = RSeq::incr(1.0f64).map(|n| 1.0 / (n * n)).partial_sums();
+        let sum = basel.limit(1e-8, 1_000_000).unwrap();
+        assert!((sum - std::f64::consts::PI.powi(2) / 6.0).abs() < 1e-3);
+        assert_eq!(RSeq::incr(0.0).limit(0.5, 1000), None);
+        let stops = RSeq::cons(
+            1.0,
+            RSeq::cons(1.0, RSeq::lazy(|| panic!("forced too far"))),
+        );
+        assert_eq!(stops.limit(0.1, 10), Some(1.0));
+    }
+}
@@ -0,0 +1,223 @@
+use alloc::{rc::Rc, vec::Vec};
+use core::{cell::RefCell, ops::Add};
+
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+
+use crate::{error::Error, iter::IntoRSeq, seq::RSeq};
+
+impl<T> RSeq<T>
+where
+    T: Clone + 'static,
+{
+    /// Values sampled from `rng` with the [`Standard`] distribution.
+    ///
+    /// Each element is drawn the first time it is demanded and cached, so a
+    /// seeded `rng` always gives the same sequence, however often its elements
+    /// are read.
+    pub fn random<R>(rng: R) -> Self
+    where
+        R: Rng + 'static,
+        Standard: Distribution<T>,
+    {
+        Self::sample_from(rng, Standard)
+    }
+
+    /// Values sampled from `dist` using `rng`, drawn and cached like
+    /// [`RSeq::random`].
+    pub fn sample_from<R, D>(rng: R, dist: D) -> Self
+    where
+        R: Rng + 'static,
+        D: Distribution<T> + 'static,
+    {
+        dist.sample_iter(rng).into_rseq()
+    }
+}
+
+impl<T> RSeq<T>
+where
+    T: Add<Output = T> + Clone + 'static,
+{
+    /// The random walk `x[0] = start`, `x[n+1] = x[n] + s[n]`, with steps
+    /// `s[n]` sampled from `step_dist` using `rng`. Steps are drawn and cached
+    /// like [`RSeq::random`].
+    pub fn random_walk<R, D>(start: T, rng: R, step_dist: D) -> Self
+    where
+        R: Rng + 'static,
+        D: Distribution<T> + 'static,
+    {
+        let steps = Self::sample_from(rng, step_dist);
+        let rest = steps.scan(start.clone(), |x, step| {
+            let x = x + step;
+            (x.clone(), x)
+        });
+        Self::cons(start, rest)
+    }
+}
+
+impl RSeq<i64> {
+    /// The random walk from `start` taking steps of `+1` or `-1` with equal
+    /// probability.
+    pub fn coin_walk<R>(start: i64, rng: R) -> Self
+    where
+        R: Rng + 'static,
+    {
+        let coin = Standard.map(|up: bool| if up { 1 } else { -1 });
+        Self::random_walk(start, rng, coin)
+    }
+}
+
+impl RSeq<usize> {
+    /// The states of a Markov chain that starts in `initial` and moves from
+    /// state `i` to state `j` with probability `transition[i][j]`. Each move
+    /// is drawn from `rng` when its element is first demanded and cached.
+    ///
+    /// Returns [`Error::EmptyInput`] if there are no states,
+    /// [`Error::LengthMismatch`] if `transition` is not square,
+    /// [`Error::OutOfRange`] if `initial` is not a state, and
+    /// [`Error::NotStochastic`] if a row has a negative entry or does not sum
+    /// to one within `1e-9`.
+    pub fn markov<R>(initial: usize, transition: Vec<Vec<f64>>, rng: R) -> Result<Self, Error>
+    where
+        R: Rng + 'static,
+    {
+        let n = transition.len();
+        if n == 0 {
+            return Err(Error::EmptyInput);
+        }
+        for (i, row) in transition.iter().enumerate() {
+            if row.len() != n {
+                return Err(Error::LengthMismatch {
+                    expected: n,
+                    found: row.len(),
+                });
+            }
+            let sum: f64 = row.iter().sum();
+            let stochastic = row.iter().all(|&p| p >= 0.0) && (sum - 1.0).abs() <= 1e-9;
+            if !stochastic {
+                return Err(Error::NotStochastic { row: i });
+            }
+        }
+        if initial >= n {
+            return Err(Error::OutOfRange {
+                index: initial,
+                len: n,
+            });
+        }
+        Ok(Self::markov_from(
+            initial,
+            transition.into(),
+            Rc::new(RefCell::new(rng)),
+        ))
+    }
+
+    fn markov_from<R>(state: usize, transition: Rc<[Vec<f64>]>, rng: Rc<RefCell<R>>) -> Self
+    where
+        R: Rng + 'static,
+    {
+        Self::lazy(move || {
+            let row = &transition[state];
+            let u: f64 = rng.borrow_mut().gen();
+            let mut total = 0.0;
+            // Rounding can leave the row's total just under `u`; fall back to
+            // the last state that can be reached.
+            let next = row
+                .iter()
+                .position(|&p| {
+                    total += p;
+                    u < total
+                })
+                .unwrap_or_else(|| row.iter().rposition(|&p| p > 0.0).unwrap());
+            (state, Self::markov_from(next, transition, rng))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{distributions::Uniform, rngs::StdRng, SeedableRng};
+
+    use crate::{prelude::*, Error};
+
+    #[test]
+    fn random() {
+        let s = RSeq::<u32>::random(StdRng::seed_from_u64(7));
+        assert_eq!(s.take(20), s.take(20));
+        assert_eq!(s.tail().take(5), s.take(6)[1..]);
+        let t = RSeq::<u32>::random(StdRng::seed_from_u64(7));
+        assert_eq!(t.take(20), s.take(20));
+        let mean = RSeq::<f64>::random(StdRng::seed_from_u64(1)).sum_n(1000) / 1000.0;
+        assert!((mean - 0.5).abs() < 0.05);
+    }
+
+    #[test]
+    fn sample_from() {
+        let dice = RSeq::sample_from(StdRng::seed_from_u64(3), Uniform::new_inclusive(1, 6));
+        assert!(dice.all_n(500, |n| (1..=6).contains(n)));
+        for face in 1..=6 {
+            assert!(dice.any_n(500, |&n| n == face));
+        }
+    }
+
+    #[test]
+    fn coin_walk() {
+        let walk = RSeq::coin_walk(0, StdRng::seed_from_u64(42));
+        assert_eq!(walk.take(10), vec![0, -1, 0, -1, 0, 1, 2, 3, 2, 3]);
+        assert_eq!(walk.take(10), walk.take(10));
+        assert!(walk.differences().all_n(100, |d| d.abs() == 1));
+        let other = RSeq::coin_walk(0, StdRng::seed_from_u64(43));
+        assert!(!walk.eq_prefix(&other, 20));
+    }
+
+    #[test]
+    fn random_walk() {
+        let walk = RSeq::random_walk(10.0, StdRng::seed_from_u64(5), Uniform::new(0.0, 1.0));
+        assert_eq!(*walk.head(), 10.0);
+        assert!(walk.differences().all_n(100, |d| (0.0..1.0).contains(d)));
+    }
+
+    #[test]
+    fn markov() {
+        let flip = vec![vec![0.0, 1.0], vec![1.0, 0.0]];
+        let chain = RSeq::markov(0, flip, StdRng::seed_from_u64(0)).unwrap();
+        assert_eq!(chain.take(5), vec![0, 1, 0, 1, 0]);
+        let sticky = vec![vec![0.9, 0.1], vec![0.5, 0.5]];
+        let chain = RSeq::markov(1, sticky.clone(), StdRng::seed_from_u64(9)).unwrap();
+        let again = RSeq::markov(1, sticky, StdRng::seed_from_u64(9)).unwrap();
+        assert!(chain.eq_prefix(&again, 50));
+        let labels = chain.map(|s| ["sunny", "rainy"][s]);
+        assert_eq!(*labels.head(), "rainy");
+    }
+
+    #[test]
+    fn markov_invalid() {
+        let rng = || StdRng::seed_from_u64(0);
+        let err = |m: Vec<Vec<f64>>| RSeq::markov(0, m, rng()).err();
+        assert_eq!(err(vec![]), Some(Error::EmptyInput));
+        assert_eq!(
+            err(vec![vec![1.0], vec![0.0, 1.0]]),
+            Some(Error::LengthMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            err(vec![vec![0.5, 0.4], vec![0.0, 1.0]]),
+            Some(Error::NotStochastic { row: 0 })
+        );
+        assert_eq!(
+            err(vec![vec![1.0, 0.0], vec![1.5, -0.5]]),
+            Some(Error::NotStochastic { row: 1 })
+        );
+        assert_eq!(
+            err(vec![vec![f64::NAN]]),
+            Some(Error::NotStochastic { row: 0 })
+        );
+        assert_eq!(
+            RSeq::markov(2, vec![vec![1.0]], rng()).err(),
+            Some(Error::OutOfRange { index: 2, len: 1 })
+        );
+    }
+}
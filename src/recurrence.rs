@@ -0,0 +1,333 @@
+use alloc::{rc::Rc, vec::Vec};
+use core::ops::Index;
+
+use num_traits::Num;
+
+use crate::{error::Error, seq::RSeq};
+
+impl<T> RSeq<T>
+where
+    T: Num + Clone + 'static,
+{
+    /// The sequence `a` with `a[n] = c[0]·a[n-1] + c[1]·a[n-2] + ... +
+    /// c[k-1]·a[n-k]`, starting with the `k` terms in `init`.
+    ///
+    /// Returns [`Error::EmptyInput`] if there are no coefficients and
+    /// [`Error::LengthMismatch`] if `init` doesn't have one term per
+    /// coefficient.
+    pub fn linear_recurrence(coeffs: Vec<T>, init: Vec<T>) -> Result<Self, Error> {
+        LinearRecurrence::new(coeffs, init).map(|rec| rec.as_rseq())
+    }
+
+    /// Continues a linear recurrence whose last `k` terms, oldest first, are
+    /// in `window`.
+    fn recurrence_from(coeffs: Rc<[T]>, mut window: Vec<T>) -> Self {
+        Self::lazy(move || {
+            let next = coeffs
+                .iter()
+                .zip(window.iter().rev())
+                .fold(T::zero(), |acc, (c, a)| acc + c.clone() * a.clone());
+            let out = window.remove(0);
+            window.push(next);
+            (out, Self::recurrence_from(coeffs, window))
+        })
+    }
+}
+
+/// A linear recurrence `a[n] = c[0]·a[n-1] + ... + c[k-1]·a[n-k]` with its
+/// initial terms, which can compute a single term without computing the ones
+/// before it.
+#[derive(Debug, Clone)]
+pub struct LinearRecurrence<T> {
+    coeffs: Rc<[T]>,
+    init: Vec<T>,
+}
+
+impl<T> LinearRecurrence<T>
+where
+    T: Num + Clone + 'static,
+{
+    /// The recurrence with coefficients `coeffs`, starting with the `k` terms
+    /// in `init`.
+    ///
+    /// Returns [`Error::EmptyInput`] if there are no coefficients and
+    /// [`Error::LengthMismatch`] if `init` doesn't have one term per
+    /// coefficient.
+    pub fn new(coeffs: Vec<T>, init: Vec<T>) -> Result<Self, Error> {
+        if coeffs.is_empty() {
+            return Err(Error::EmptyInput);
+        }
+        if init.len() != coeffs.len() {
+            return Err(Error::LengthMismatch {
+                expected: coeffs.len(),
+                found: init.len(),
+            });
+        }
+        Ok(Self {
+            coeffs: coeffs.into(),
+            init,
+        })
+    }
+
+    /// The recurrence whose right-hand side is the sum of `c·a[n-k]` over the
+    /// pairs `(k, c)` in `terms`, with one coefficient per initial term. Used
+    /// by [`recurrence!`](crate::recurrence).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `init` is empty or some lag `k` is zero or exceeds its
+    /// length.
+    #[doc(hidden)]
+    pub fn from_lags(init: Vec<T>, terms: &[(usize, T)]) -> Self {
+        let mut coeffs: Vec<T> = init.iter().map(|_| T::zero()).collect();
+        for (k, c) in terms {
+            coeffs[k - 1] = coeffs[k - 1].clone() + c.clone();
+        }
+        Self::new(coeffs, init).expect("recurrence! has at least one initial term")
+    }
+
+    /// The terms of the recurrence as a lazy sequence, as built by
+    /// [`RSeq::linear_recurrence`].
+    pub fn as_rseq(&self) -> RSeq<T> {
+        RSeq::recurrence_from(Rc::clone(&self.coeffs), self.init.clone())
+    }
+
+    /// Term `n`, computed by raising the recurrence's `k × k` companion
+    /// matrix to the `n`th power by repeated squaring, in `O(k³ log n)`
+    /// arithmetic operations.
+    pub fn nth(&self, n: u64) -> T {
+        self.nth_with(n, |x| x)
+    }
+
+    /// Term `n` modulo `m`, reducing after every operation so that no
+    /// intermediate value exceeds `m²` by much. For signed `T` the result has
+    /// the sign `%` gives it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is zero and `T` is an integer type.
+    pub fn nth_mod(&self, n: u64, m: T) -> T {
+        self.nth_with(n, |x| x % m.clone())
+    }
+
+    fn nth_with(&self, mut n: u64, reduce: impl Fn(T) -> T) -> T {
+        let k = self.coeffs.len();
+        // Row `i < k - 1` shifts term `i + 1` of the state into place; the
+        // last row computes the next term.
+        let mut base: Matrix<T> = (0..k)
+            .map(|i| {
+                (0..k)
+                    .map(|j| {
+                        if i == k - 1 {
+                            reduce(self.coeffs[k - 1 - j].clone())
+                        } else if j == i + 1 {
+                            T::one()
+                        } else {
+                            T::zero()
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        let mut power = identity(k);
+        while n > 0 {
+            if n % 2 == 1 {
+                power = mat_mul(&power, &base, &reduce);
+            }
+            n /= 2;
+            // Squaring once more than needed could overflow for no reason.
+            if n > 0 {
+                base = mat_mul(&base, &base, &reduce);
+            }
+        }
+        power[0]
+            .iter()
+            .zip(&self.init)
+            .fold(T::zero(), |acc, (p, a)| {
+                reduce(acc + reduce(p.clone() * reduce(a.clone())))
+            })
+    }
+}
+
+/// A square matrix, stored row by row.
+type Matrix<T> = Vec<Vec<T>>;
+
+fn identity<T: Num>(k: usize) -> Matrix<T> {
+    (0..k)
+        .map(|i| {
+            (0..k)
+                .map(|j| if i == j { T::one() } else { T::zero() })
+                .collect()
+        })
+        .collect()
+}
+
+fn mat_mul<T: Num + Clone>(a: &Matrix<T>, b: &Matrix<T>, reduce: &impl Fn(T) -> T) -> Matrix<T> {
+    let k = a.len();
+    (0..k)
+        .map(|i| {
+            (0..k)
+                .map(|j| {
+                    (0..k).fold(T::zero(), |acc, l| {
+                        reduce(acc + reduce(a[i][l].clone() * b[l][j].clone()))
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+impl<T> RSeq<T>
+where
+    T: Clone + 'static,
+{
+    /// The sequence starting with the `k` terms in `init`, each later term
+    /// `a[n]` being `f(n, window)` where `window` holds `a[n-k]` to `a[n-1]`,
+    /// oldest first. This is the form [`recurrence!`](crate::recurrence)
+    /// builds on.
+    ///
+    /// Returns [`Error::EmptyInput`] if `init` is empty.
+    pub fn window_recurrence(
+        init: Vec<T>,
+        f: impl Fn(usize, &[T]) -> T + 'static,
+    ) -> Result<Self, Error> {
+        if init.is_empty() {
+            return Err(Error::EmptyInput);
+        }
+        Ok(Self::window_from(0, init, Rc::new(f)))
+    }
+
+    /// Continues a window recurrence whose terms `n` to `n + k - 1` are in
+    /// `window`.
+    fn window_from<F>(n: usize, mut window: Vec<T>, f: Rc<F>) -> Self
+    where
+        F: Fn(usize, &[T]) -> T + 'static,
+    {
+        Self::lazy(move || {
+            let next = f(n + window.len(), &window);
+            let out = window.remove(0);
+            window.push(next);
+            (out, Self::window_from(n + 1, window, f))
+        })
+    }
+}
+
+/// The terms `a[n-k]` to `a[n-1]` of a window recurrence, indexed by their
+/// position in the whole sequence. Used by [`recurrence!`](crate::recurrence).
+#[doc(hidden)]
+pub struct Window<'a, T> {
+    start: usize,
+    terms: &'a [T],
+}
+
+impl<'a, T> Window<'a, T> {
+    pub fn new(n: usize, terms: &'a [T]) -> Self {
+        Self {
+            start: n - terms.len(),
+            terms,
+        }
+    }
+}
+
+/// Whether two identifiers, as given by `stringify!`, are the same. Used by
+/// [`recurrence!`](crate::recurrence) to tell terms of the sequence apart
+/// from other indexing at compile time.
+#[doc(hidden)]
+pub const fn same_ident(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+impl<T> Index<usize> for Window<'_, T> {
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        i.checked_sub(self.start)
+            .and_then(|j| self.terms.get(j))
+            .expect("recurrence refers to a term outside its window")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, Error, LinearRecurrence};
+
+    #[test]
+    fn fibonacci() {
+        let s = RSeq::linear_recurrence(vec![1, 1], vec![0, 1]).unwrap();
+        assert_eq!(s.take(10), vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+    }
+
+    #[test]
+    fn tribonacci() {
+        let s = RSeq::linear_recurrence(vec![1, 1, 1], vec![0, 0, 1]).unwrap();
+        assert_eq!(s.take(10), vec![0, 0, 1, 1, 2, 4, 7, 13, 24, 44]);
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            RSeq::linear_recurrence(vec![1, 1], vec![0]).err(),
+            Some(Error::LengthMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            RSeq::<i32>::linear_recurrence(vec![], vec![]).err(),
+            Some(Error::EmptyInput)
+        );
+    }
+
+    #[test]
+    fn window_recurrence() {
+        let factorials = RSeq::window_recurrence(vec![1u64], |n, w| w[0] * n as u64).unwrap();
+        assert_eq!(factorials.take(6), vec![1, 1, 2, 6, 24, 120]);
+        let fibs = RSeq::window_recurrence(vec![0, 1], |_, w| w[0] + w[1]).unwrap();
+        assert!(fibs.eq_prefix(
+            &RSeq::linear_recurrence(vec![1, 1], vec![0, 1]).unwrap(),
+            40
+        ));
+        assert_eq!(
+            RSeq::<i32>::window_recurrence(vec![], |_, w| w[0]).err(),
+            Some(Error::EmptyInput)
+        );
+    }
+
+    #[test]
+    fn linear_recurrence_nth() {
+        let fibs = LinearRecurrence::new(vec![1u64, 1], vec![0, 1]).unwrap();
+        assert_eq!(fibs.nth(0), 0);
+        assert_eq!(fibs.nth(1), 1);
+        assert_eq!(fibs.nth(90), 2_880_067_194_370_816_120);
+        let lazy = fibs.as_rseq().take(30);
+        assert_eq!((0..30).map(|n| fibs.nth(n)).collect::<Vec<_>>(), lazy);
+        let tribs = LinearRecurrence::new(vec![2i64, -1, 3], vec![1, -2, 5]).unwrap();
+        let lazy = tribs.as_rseq().take(20);
+        assert_eq!((0..20).map(|n| tribs.nth(n)).collect::<Vec<_>>(), lazy);
+    }
+
+    #[test]
+    fn linear_recurrence_nth_mod() {
+        const M: u64 = 1_000_000_007;
+        let fibs = LinearRecurrence::new(vec![1u64, 1], vec![0, 1]).unwrap();
+        assert_eq!(fibs.nth_mod(1_000_000_000_000, M), 730_695_249);
+        assert_eq!(fibs.nth_mod(90, M), 2_880_067_194_370_816_120 % M);
+        let tribs = LinearRecurrence::new(vec![1u64, 1, 1], vec![0, 0, 1]).unwrap();
+        assert_eq!(tribs.nth_mod(100, M), 180_948_981);
+        assert_eq!(
+            LinearRecurrence::<u64>::new(vec![], vec![]).err(),
+            Some(Error::EmptyInput)
+        );
+    }
+}
@@ -0,0 +1,279 @@
+use alloc::{rc::Rc, vec::Vec};
+
+use crate::thunk::Thunk;
+
+/// A lazily evaluated infinite sequence.
+///
+/// Nothing about an element is computed until it is first demanded, and each
+/// element is computed at most once; clones of a sequence share that work.
+pub struct RSeq<T> {
+    node: Rc<Thunk<Node<T>>>,
+}
+
+struct Node<T> {
+    head: T,
+    tail: RSeq<T>,
+}
+
+impl<T> Clone for RSeq<T> {
+    fn clone(&self) -> Self {
+        Self {
+            node: Rc::clone(&self.node),
+        }
+    }
+}
+
+impl<T> Drop for RSeq<T> {
+    fn drop(&mut self) {
+        // Unlink forced nodes one at a time so that dropping a long chain of
+        // cached elements doesn't recurse once per element.
+        let mut next = Rc::get_mut(&mut self.node).and_then(Thunk::take);
+        while let Some(mut node) = next {
+            next = Rc::get_mut(&mut node.tail.node).and_then(Thunk::take);
+        }
+    }
+}
+
+impl<T> RSeq<T> {
+    /// Builds a sequence whose head and tail are computed by `f` when first
+    /// demanded.
+    pub(crate) fn lazy(f: impl FnOnce() -> (T, Self) + Clone + 'static) -> Self {
+        Self {
+            node: Rc::new(Thunk::new(move || {
+                let (head, tail) = f();
+                Node { head, tail }
+            })),
+        }
+    }
+
+    /// Puts `head` in front of `tail` without forcing anything in `tail`.
+    pub fn cons(head: T, tail: Self) -> Self {
+        Self {
+            node: Rc::new(Thunk::ready(Node { head, tail })),
+        }
+    }
+
+    /// Yields the elements of `prefix` in order, then continues with `self`.
+    /// Nothing in `self` is forced.
+    pub fn prepend(&self, prefix: Vec<T>) -> Self {
+        prefix
+            .into_iter()
+            .rev()
+            .fold(self.clone(), |tail, head| Self::cons(head, tail))
+    }
+
+    /// Returns the first element of the sequence, computing it if needed.
+    pub fn head(&self) -> &T {
+        &self.node.force().head
+    }
+
+    /// Returns the sequence following the head. The tail's own elements are
+    /// not computed until they are demanded.
+    pub fn tail(&self) -> Self {
+        self.node.force().tail.clone()
+    }
+
+    /// Iterates over the sequence itself followed by each successive tail.
+    /// Producing a suffix computes the head of the one before it.
+    pub(crate) fn suffixes(&self) -> impl Iterator<Item = Self> {
+        core::iter::successors(Some(self.clone()), |seq| Some(seq.tail()))
+    }
+}
+
+impl<T> RSeq<T>
+where
+    T: Clone + 'static,
+{
+    /// Builds a sequence that behaves like the one returned by `f`, calling
+    /// `f` only when the head is first demanded.
+    ///
+    /// This delays building a sequence that refers to itself, for example
+    /// inside a [`RSeq::fix`] definition.
+    pub fn defer(f: impl FnOnce() -> Self + Clone + 'static) -> Self {
+        Self::lazy(move || {
+            let seq = f();
+            (seq.head().clone(), seq.tail())
+        })
+    }
+
+    /// Collects the first `n` elements into a `Vec`.
+    pub fn take(&self, n: usize) -> Vec<T> {
+        let mut out = Vec::with_capacity(n);
+        if n == 0 {
+            return out;
+        }
+        out.push(self.head().clone());
+        let mut curr = self.clone();
+        for _ in 1..n {
+            curr = curr.tail();
+            out.push(curr.head().clone());
+        }
+        out
+    }
+
+    /// Collects elements up to, but not including, the first one failing
+    /// `pred`. No tail past the failing element is forced.
+    pub fn take_while(&self, pred: impl Fn(&T) -> bool) -> Vec<T> {
+        let mut out = Vec::new();
+        let mut curr = self.clone();
+        while pred(curr.head()) {
+            out.push(curr.head().clone());
+            curr = curr.tail();
+        }
+        out
+    }
+
+    /// Returns the sequence starting at element `n`, forcing exactly `n` tails.
+    pub fn skip(&self, n: usize) -> Self {
+        let mut curr = self.clone();
+        for _ in 0..n {
+            curr = curr.tail();
+        }
+        curr
+    }
+
+    /// Returns element `n` without collecting the elements before it.
+    pub fn nth(&self, n: usize) -> T {
+        self.skip(n).head().clone()
+    }
+
+    /// Returns element `n` together with the sequence following it.
+    pub fn nth_seq(&self, n: usize) -> (T, Self) {
+        let at = self.skip(n);
+        (at.head().clone(), at.tail())
+    }
+
+    /// Returns the sequence starting at the first element failing `pred`.
+    /// The search runs when the result's head is first demanded.
+    ///
+    /// Loops forever if every element satisfies `pred`.
+    pub fn skip_while(&self, pred: impl Fn(&T) -> bool + 'static) -> Self {
+        let start = self.clone();
+        let pred = Rc::new(pred);
+        Self::defer(move || {
+            let mut curr = start;
+            while pred(curr.head()) {
+                curr = curr.tail();
+            }
+            curr
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use crate::prelude::*;
+
+    #[test]
+    fn cons() {
+        assert_eq!(RSeq::cons(99, RSeq::incr(0)).take(4), vec![99, 0, 1, 2]);
+        let s = RSeq::cons(1, RSeq::cons(2, RSeq::cons(3, RSeq::cnst(0))));
+        assert_eq!(s.take(5), vec![1, 2, 3, 0, 0]);
+        let lazy = RSeq::cons(5, RSeq::lazy(|| panic!("tail forced")));
+        assert_eq!(*lazy.head(), 5);
+    }
+
+    #[test]
+    fn prepend() {
+        let s = RSeq::incr(10);
+        assert_eq!(s.prepend(vec![1, 2, 3]).take(5), vec![1, 2, 3, 10, 11]);
+        assert_eq!(s.prepend(vec![]).take(3), s.take(3));
+        let lazy = RSeq::lazy(|| panic!("sequence forced")).prepend(vec![1, 2]);
+        assert_eq!(lazy.take(2), vec![1, 2]);
+    }
+
+    #[test]
+    fn take() {
+        let s = RSeq::lazy(|| (1, RSeq::lazy(|| (2, RSeq::lazy(|| panic!("tail forced"))))));
+        assert_eq!(s.take(0), Vec::<i32>::new());
+        assert_eq!(s.take(1), vec![1]);
+        assert_eq!(s.take(2), vec![1, 2]);
+    }
+
+    #[test]
+    fn take_clones_once() {
+        thread_local! {
+            static CLONES: Cell<usize> = const { Cell::new(0) };
+        }
+        struct Tracked(usize);
+        impl Clone for Tracked {
+            fn clone(&self) -> Self {
+                CLONES.with(|c| c.set(c.get() + 1));
+                Tracked(self.0)
+            }
+        }
+        let s = RSeq::from_fn(Tracked);
+        assert_eq!(s.take(5).len(), 5);
+        assert_eq!(CLONES.with(Cell::get), 5);
+        assert_eq!(s.map(|t| t.0 * 2).take(5), vec![0, 2, 4, 6, 8]);
+        assert_eq!(CLONES.with(Cell::get), 10);
+    }
+
+    #[test]
+    fn take_while() {
+        let s = RSeq::incr(0);
+        assert_eq!(s.take_while(|&n| n < 5), vec![0, 1, 2, 3, 4]);
+        assert_eq!(s.take_while(|&n| n < 0), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn take_while_stops_at_failure() {
+        let s = RSeq::lazy(|| {
+            (
+                1,
+                RSeq::lazy(|| (2, RSeq::lazy(|| panic!("forced past failure")))),
+            )
+        });
+        assert_eq!(s.take_while(|&n| n < 2), vec![1]);
+    }
+
+    #[test]
+    fn skip() {
+        let s = RSeq::incr(0).map(|n| n * n);
+        assert_eq!(s.skip(0).take(3), vec![0, 1, 4]);
+        assert_eq!(s.skip(3).take(3), vec![9, 16, 25]);
+        assert_eq!((&s.skip(1) - &s).take(5), vec![1, 3, 5, 7, 9]);
+        assert_eq!(*RSeq::incr(0).skip(1_000_000).head(), 1_000_000);
+    }
+
+    #[test]
+    fn memoized_tails() {
+        thread_local! {
+            static CALLS: Cell<usize> = const { Cell::new(0) };
+        }
+        let s = RSeq::incr(0).map(|n| {
+            CALLS.with(|c| c.set(c.get() + 1));
+            n * 2
+        });
+        assert_eq!(s.take(5), vec![0, 2, 4, 6, 8]);
+        assert_eq!(s.clone().into_iter().take(5).sum::<i32>(), 20);
+        assert_eq!(s.tail().take(4), vec![2, 4, 6, 8]);
+        assert_eq!(CALLS.with(Cell::get), 5);
+    }
+
+    #[test]
+    fn drop_long_chain() {
+        let s = RSeq::incr(0);
+        assert_eq!(s.nth(1_000_000), 1_000_000);
+        drop(s);
+    }
+
+    #[test]
+    fn skip_while() {
+        let s = RSeq::incr(0).skip_while(|&n| n < 1000);
+        assert_eq!(s.take(3), vec![1000, 1001, 1002]);
+        assert_eq!(RSeq::incr(5).skip_while(|&n| n < 0).take(2), vec![5, 6]);
+    }
+
+    #[test]
+    fn nth() {
+        assert_eq!(RSeq::incr(0).nth(100_000), 100_000);
+        let s = RSeq::lazy(|| (7, RSeq::lazy(|| panic!("tail forced"))));
+        assert_eq!(s.nth(0), 7);
+        let (n, rest) = RSeq::incr(0).nth_seq(3);
+        assert_eq!(n, 3);
+        assert_eq!(rest.take(2), vec![4, 5]);
+    }
+}
@@ -0,0 +1,106 @@
+use alloc::vec::Vec;
+use core::ops::{Add, Mul};
+
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::{error::Error, seq::RSeq};
+
+/// Serializes the first few elements of a sequence as a list, forcing no
+/// others. Created by [`RSeq::prefix`].
+pub struct Prefix<'a, T> {
+    seq: &'a RSeq<T>,
+    n: usize,
+}
+
+impl<T> RSeq<T> {
+    /// Returns a wrapper that serializes the first `n` elements as a list.
+    pub fn prefix(&self, n: usize) -> Prefix<'_, T> {
+        Prefix { seq: self, n }
+    }
+}
+
+impl<T> Serialize for Prefix<'_, T>
+where
+    T: Serialize + Clone + 'static,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.seq.take_iter(self.n))
+    }
+}
+
+/// A description of a generated sequence, for reading from configuration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SeqSpec<T> {
+    /// [`RSeq::cnst`].
+    Const(T),
+    /// [`RSeq::arithmetic`].
+    Arithmetic { start: T, step: T },
+    /// [`RSeq::geometric`].
+    Geometric { start: T, ratio: T },
+    /// [`RSeq::cycle`].
+    Cycle(Vec<T>),
+}
+
+impl<T> SeqSpec<T>
+where
+    T: Add<Output = T> + Mul<Output = T> + Clone + 'static,
+{
+    /// Builds the described sequence.
+    ///
+    /// Returns [`Error::EmptyInput`] for a `Cycle` with no elements.
+    pub fn build(self) -> Result<RSeq<T>, Error> {
+        match self {
+            SeqSpec::Const(v) => Ok(RSeq::cnst(v)),
+            SeqSpec::Arithmetic { start, step } => Ok(RSeq::arithmetic(start, step)),
+            SeqSpec::Geometric { start, ratio } => Ok(RSeq::geometric(start, ratio)),
+            SeqSpec::Cycle(items) => RSeq::cycle(items),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, Error, SeqSpec};
+
+    #[test]
+    fn prefix() {
+        let evens = RSeq::incr(0).filter(|n| n % 2 == 0);
+        let json = serde_json::to_string(&evens.prefix(5)).unwrap();
+        assert_eq!(json, "[0,2,4,6,8]");
+        let lazy = RSeq::cons(1, RSeq::lazy(|| panic!("forced too far")));
+        assert_eq!(serde_json::to_string(&lazy.prefix(1)).unwrap(), "[1]");
+        assert_eq!(serde_json::to_string(&lazy.prefix(0)).unwrap(), "[]");
+    }
+
+    #[test]
+    fn spec_round_trip() {
+        let specs = [
+            (SeqSpec::Const(4), RSeq::cnst(4)),
+            (
+                SeqSpec::Arithmetic { start: 1, step: 3 },
+                RSeq::arithmetic(1, 3),
+            ),
+            (
+                SeqSpec::Geometric { start: 1, ratio: 2 },
+                RSeq::geometric(1, 2),
+            ),
+            (
+                SeqSpec::Cycle(vec![1, 2, 3]),
+                RSeq::cycle(vec![1, 2, 3]).unwrap(),
+            ),
+        ];
+        for (spec, expected) in specs {
+            let json = serde_json::to_string(&spec).unwrap();
+            let parsed: SeqSpec<i64> = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed, spec);
+            assert!(parsed.build().unwrap().eq_prefix(&expected, 10));
+        }
+        let config = r#"{"Arithmetic": {"start": 0.5, "step": 0.25}}"#;
+        let spec: SeqSpec<f64> = serde_json::from_str(config).unwrap();
+        assert_eq!(spec.build().unwrap().take(3), vec![0.5, 0.75, 1.0]);
+        assert_eq!(
+            SeqSpec::<i32>::Cycle(vec![]).build().err(),
+            Some(Error::EmptyInput)
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use alloc::{rc::Rc, vec::Vec};
+use core::cell::RefCell;
+
+use num_traits::Num;
+
+use crate::{diagonal::Prefixes, seq::RSeq};
+
+/// Operations treating a sequence `c` as the coefficients of the formal power
+/// series `c[0] + c[1]·x + c[2]·x² + ...`.
+impl<T> RSeq<T>
+where
+    T: Num + Clone + 'static,
+{
+    /// The coefficients of the product of two power series (their Cauchy
+    /// product), `c[n] = a[0]·b[n] + a[1]·b[n-1] + ... + a[n]·b[0]`.
+    ///
+    /// The prefixes of both inputs are kept as the result is forced, so
+    /// coefficient `n` costs `O(n)`.
+    pub fn series_mul(&self, other: &Self) -> Self {
+        let prefixes = Rc::new(RefCell::new((Vec::new(), Vec::new())));
+        Self::cauchy_from(self.clone(), other.clone(), prefixes, 0)
+    }
+
+    /// Continues the product at coefficient `n`. The prefixes are shared by
+    /// every coefficient and hold at least the first `n` elements of each
+    /// input.
+    fn cauchy_from(a: Self, b: Self, prefixes: Prefixes<T, T>, n: usize) -> Self {
+        Self::lazy(move || {
+            if prefixes.borrow().0.len() == n {
+                let heads = (a.head().clone(), b.head().clone());
+                let (a_prefix, b_prefix) = &mut *prefixes.borrow_mut();
+                a_prefix.push(heads.0);
+                b_prefix.push(heads.1);
+            }
+            let coeff = {
+                let (a_prefix, b_prefix) = &*prefixes.borrow();
+                a_prefix[..=n]
+                    .iter()
+                    .zip(b_prefix[..=n].iter().rev())
+                    .fold(T::zero(), |acc, (x, y)| acc + x.clone() * y.clone())
+            };
+            (
+                coeff,
+                Self::cauchy_from(a.tail(), b.tail(), prefixes, n + 1),
+            )
+        })
+    }
+
+    /// The coefficients of the derivative, `c[n] ↦ (n + 1)·c[n + 1]`.
+    pub fn series_derivative(&self) -> Self {
+        let seq = self.clone();
+        Self::defer(move || {
+            seq.tail()
+                .scan(T::one(), |k, c| (k.clone() + T::one(), k * c))
+        })
+    }
+
+    /// The coefficients of the integral with constant term `constant`,
+    /// `c[n] ↦ c[n - 1] / n`.
+    pub fn series_integral(&self, constant: T) -> Self {
+        let seq = self.clone();
+        Self::lazy(move || {
+            (
+                constant,
+                seq.scan(T::one(), |k, c| (k.clone() + T::one(), c / k)),
+            )
+        })
+    }
+
+    /// The partial sums of the series at `x`: element `n` is
+    /// `c[0] + c[1]·x + ... + c[n]·xⁿ`. The power of `x` is carried along, so
+    /// each element costs one multiply-add.
+    pub fn eval_series(&self, x: T) -> Self {
+        self.eval_from(x, T::zero(), T::one())
+    }
+
+    fn eval_from(&self, x: T, sum: T, power: T) -> Self {
+        let seq = self.clone();
+        Self::lazy(move || {
+            let sum = sum + seq.head().clone() * power.clone();
+            let power = power * x.clone();
+            (sum.clone(), seq.tail().eval_from(x, sum, power))
+        })
+    }
+
+    /// The coefficients of the composition `self(inner(x))`.
+    ///
+    /// Uses `f(g) = f[0] + g·(f[1] + g·(f[2] + ...))`; with `g[0] = 0`, each
+    /// nested level contributes one higher power of `x`, so coefficient `n`
+    /// depends on only `n` levels.
+    ///
+    /// # Panics
+    ///
+    /// Demanding an element panics if `inner` has a non-zero constant term.
+    pub fn series_compose(&self, inner: &Self) -> Self {
+        let outer = self.clone();
+        let inner = inner.clone();
+        Self::lazy(move || {
+            assert!(
+                inner.head().is_zero(),
+                "inner series must have a zero constant term"
+            );
+            let rest = inner
+                .tail()
+                .series_mul(&outer.tail().series_compose(&inner));
+            (outer.head().clone(), rest)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn series_mul() {
+        let ones = RSeq::cnst(1);
+        let one_minus_x = RSeq::from_fn(|n| match n {
+            0 => 1,
+            1 => -1,
+            _ => 0,
+        });
+        assert_eq!(ones.series_mul(&ones).take(5), vec![1, 2, 3, 4, 5]);
+        assert_eq!(ones.series_mul(&one_minus_x).take(5), vec![1, 0, 0, 0, 0]);
+        assert_eq!(
+            RSeq::incr(1).series_mul(&one_minus_x).take(5),
+            vec![1, 1, 1, 1, 1]
+        );
+    }
+
+    #[test]
+    fn series_derivative() {
+        let ones = RSeq::cnst(1);
+        assert_eq!(ones.series_derivative().take(5), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn series_integral() {
+        let s = RSeq::from_fn(|n| n as f64 * 0.5 + 1.0);
+        assert_eq!(
+            s.series_integral(3.0).take(4),
+            vec![3.0, 1.0, 1.5 / 2.0, 2.0 / 3.0]
+        );
+        assert_eq!(
+            s.series_integral(3.0).series_derivative().take(10),
+            s.take(10)
+        );
+    }
+
+    #[test]
+    fn eval_series() {
+        let exp = RSeq::incr(1.0f64).cumulative_product().prepend(vec![1.0]);
+        let exp = exp.map(|f| 1.0 / f);
+        let e = exp.eval_series(1.0).limit(1e-15, 100).unwrap();
+        assert!((e - std::f64::consts::E).abs() < 1e-10);
+        assert_eq!(exp.eval_series(0.0).take(4), vec![1.0; 4]);
+        let poly = RSeq::cnst(0).prepend(vec![2, 0, 3]);
+        assert_eq!(poly.eval_series(2).take(4), vec![2, 2, 14, 14]);
+    }
+
+    #[test]
+    fn series_compose() {
+        // x / (1 - x) composed with itself is x / (1 - 2x).
+        let f = RSeq::from_fn(|n| if n == 0 { 0 } else { 1 });
+        assert_eq!(
+            f.series_compose(&f).take(8),
+            vec![0, 1, 2, 4, 8, 16, 32, 64]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "zero constant term")]
+    fn series_compose_constant_term() {
+        RSeq::cnst(1).series_compose(&RSeq::cnst(1)).take(1);
+    }
+}
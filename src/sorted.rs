@@ -0,0 +1,181 @@
+use alloc::rc::Rc;
+use core::cmp::Ordering;
+
+use crate::seq::RSeq;
+
+impl<T> RSeq<T>
+where
+    T: Clone + 'static,
+{
+    /// Merges two sequences that are sorted according to `cmp` into one
+    /// sorted sequence. On ties the element from `self` comes first.
+    ///
+    /// Only the side whose element was emitted is advanced at each step.
+    pub fn merge_by(&self, other: &Self, cmp: impl Fn(&T, &T) -> Ordering + 'static) -> Self {
+        self.merge_by_rc(other, Rc::new(cmp))
+    }
+
+    fn merge_by_rc<F>(&self, other: &Self, cmp: Rc<F>) -> Self
+    where
+        F: Fn(&T, &T) -> Ordering + 'static,
+    {
+        let left = self.clone();
+        let right = other.clone();
+        Self::lazy(move || {
+            if cmp(left.head(), right.head()) == Ordering::Greater {
+                (right.head().clone(), left.merge_by_rc(&right.tail(), cmp))
+            } else {
+                (left.head().clone(), left.tail().merge_by_rc(&right, cmp))
+            }
+        })
+    }
+}
+
+impl<T> RSeq<T>
+where
+    T: PartialOrd + Clone + 'static,
+{
+    /// Merges two non-decreasing sequences into one non-decreasing sequence.
+    pub fn merge(&self, other: &Self) -> Self {
+        self.merge_by(other, compare)
+    }
+}
+
+/// Set operations on strictly increasing sequences. Each input is advanced
+/// only as far as deciding the next output requires.
+impl<T> RSeq<T>
+where
+    T: PartialOrd + Clone + 'static,
+{
+    /// The values in either sequence, each emitted once.
+    pub fn union(&self, other: &Self) -> Self {
+        let left = self.clone();
+        let right = other.clone();
+        Self::lazy(move || match compare(left.head(), right.head()) {
+            Ordering::Less => (left.head().clone(), left.tail().union(&right)),
+            Ordering::Greater => (right.head().clone(), left.union(&right.tail())),
+            Ordering::Equal => (left.head().clone(), left.tail().union(&right.tail())),
+        })
+    }
+
+    /// The values in both sequences.
+    ///
+    /// Loops forever if the sequences share no further value.
+    pub fn intersect(&self, other: &Self) -> Self {
+        let mut left = self.clone();
+        let mut right = other.clone();
+        Self::lazy(move || loop {
+            match compare(left.head(), right.head()) {
+                Ordering::Less => left = left.tail(),
+                Ordering::Greater => right = right.tail(),
+                Ordering::Equal => {
+                    return (left.head().clone(), left.tail().intersect(&right.tail()))
+                }
+            }
+        })
+    }
+
+    /// The values in `self` but not in `other`.
+    ///
+    /// Loops forever if every further value of `self` is in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut left = self.clone();
+        let mut right = other.clone();
+        Self::lazy(move || loop {
+            match compare(left.head(), right.head()) {
+                Ordering::Less => return (left.head().clone(), left.tail().difference(&right)),
+                Ordering::Greater => right = right.tail(),
+                Ordering::Equal => {
+                    left = left.tail();
+                    right = right.tail();
+                }
+            }
+        })
+    }
+}
+
+/// Compares with `partial_cmp`, treating incomparable values as equal.
+fn compare<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    a.partial_cmp(b).unwrap_or(Ordering::Equal)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn merge() {
+        let evens = RSeq::incr(0).filter(|n| n % 2 == 0);
+        let odds = RSeq::incr(0).filter(|n| n % 2 != 0);
+        assert_eq!(evens.merge(&odds).take(10), RSeq::incr(0).take(10));
+        let desc = RSeq::incr(0).map(|n| -n);
+        assert_eq!(
+            desc.merge_by(&desc, |a, b| b.cmp(a)).take(4),
+            vec![0, 0, -1, -1]
+        );
+        let rank = Vec::from([2, 0, 1]);
+        let by_rank = RSeq::cycle(vec![1, 2, 0]).unwrap();
+        let merged = by_rank.merge_by(&by_rank, move |a, b| rank[*a].cmp(&rank[*b]));
+        assert_eq!(merged.take(4), vec![1, 1, 2, 2]);
+    }
+
+    #[test]
+    fn hamming() {
+        // The 5-smooth numbers are 1 followed by their own multiples by 2, 3
+        // and 5, here merged with duplicates dropped afterwards.
+        let hamming = RSeq::fix(|h| {
+            let (twice, thrice, five) = (h * 2u64, h * 3, h * 5);
+            RSeq::cons(1, twice.merge(&thrice).merge(&five).dedup())
+        });
+        assert_eq!(
+            hamming.take(15),
+            vec![1, 2, 3, 4, 5, 6, 8, 9, 10, 12, 15, 16, 18, 20, 24]
+        );
+        assert_eq!(hamming.nth(999), 51_200_000);
+    }
+
+    #[test]
+    fn hamming_union() {
+        // The same definition with union, which merges without duplicates.
+        let hamming = RSeq::fix(|h| {
+            let (twice, thrice, five) = (h * 2u64, h * 3, h * 5);
+            RSeq::cons(1, twice.union(&thrice).union(&five))
+        });
+        assert_eq!(
+            hamming.take(15),
+            vec![1, 2, 3, 4, 5, 6, 8, 9, 10, 12, 15, 16, 18, 20, 24]
+        );
+        assert_eq!(hamming.nth(999), 51_200_000);
+    }
+
+    #[test]
+    fn union() {
+        let twos = RSeq::arithmetic(2, 2);
+        let threes = RSeq::arithmetic(3, 3);
+        assert_eq!(twos.union(&threes).take(8), vec![2, 3, 4, 6, 8, 9, 10, 12]);
+        assert_eq!(twos.union(&twos).take(3), vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn intersect() {
+        let twos = RSeq::arithmetic(2, 2);
+        let threes = RSeq::arithmetic(3, 3);
+        assert_eq!(twos.intersect(&threes).take(4), vec![6, 12, 18, 24]);
+        let sparse = RSeq::arithmetic(0u64, 1_000_000).intersect(&RSeq::arithmetic(0, 999_999));
+        assert_eq!(sparse.take(2), vec![0, 999_999_000_000]);
+    }
+
+    #[test]
+    fn difference() {
+        let evens = RSeq::arithmetic(0, 2);
+        assert_eq!(
+            RSeq::incr(0).difference(&evens).take(5),
+            vec![1, 3, 5, 7, 9]
+        );
+        let primes = RSeq::primes();
+        assert_eq!(
+            RSeq::incr(2).difference(&primes).take(6),
+            vec![4, 6, 8, 9, 10, 12]
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use core::num::Wrapping;
+
+/// Types with a successor, as used by [`RSeq::incr`](crate::RSeq::incr).
+///
+/// Implemented for the primitive numeric types and their `Wrapping`
+/// versions, where the successor is the value plus one, and for `char`.
+/// Implement it for your own types to count through them.
+///
+/// There is no blanket impl for every [`num_traits::Num`] type: coherence
+/// would reject it next to the impl for `char`, since `num_traits` could
+/// implement `Num` for `char` in a later release. For other numeric types,
+/// such as big integers or ratios, use
+/// [`RSeq::incr_num`](crate::RSeq::incr_num).
+pub trait Step {
+    /// The value that comes after `self`.
+    fn next(&self) -> Self;
+}
+
+macro_rules! impl_step {
+    ($($t:ty),*) => {
+        $(
+            impl Step for $t {
+                fn next(&self) -> Self {
+                    *self + 1 as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_step!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+macro_rules! impl_step_wrapping {
+    ($($t:ty),*) => {
+        $(
+            impl Step for Wrapping<$t> {
+                /// The value plus one, wrapping around at the maximum.
+                fn next(&self) -> Self {
+                    *self + Wrapping(1)
+                }
+            }
+        )*
+    };
+}
+
+impl_step_wrapping!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl Step for char {
+    /// The next Unicode scalar value, skipping the surrogate range.
+    ///
+    /// # Panics
+    ///
+    /// Panics on `char::MAX`.
+    fn next(&self) -> Self {
+        match *self {
+            '\u{D7FF}' => '\u{E000}',
+            c => char::from_u32(c as u32 + 1).expect("no character follows char::MAX"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::Wrapping;
+
+    use crate::{prelude::*, Step};
+
+    #[test]
+    fn chars() {
+        assert_eq!(RSeq::incr('a').take(3), vec!['a', 'b', 'c']);
+        assert_eq!('\u{D7FF}'.next(), '\u{E000}');
+        assert_eq!('z'.next(), '{');
+    }
+
+    #[test]
+    #[should_panic(expected = "char::MAX")]
+    fn char_max() {
+        char::MAX.next();
+    }
+
+    #[test]
+    fn custom() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Id(u32);
+        impl Step for Id {
+            fn next(&self) -> Self {
+                Id(self.0 + 1)
+            }
+        }
+        assert_eq!(RSeq::incr(Id(7)).take(2), vec![Id(7), Id(8)]);
+        assert_eq!(RSeq::incr(0.5).take(3), vec![0.5, 1.5, 2.5]);
+    }
+
+    #[test]
+    fn wrapping() {
+        let s = RSeq::incr(Wrapping(254u8)).map(|w| w.0);
+        assert_eq!(s.take(4), vec![254, 255, 0, 1]);
+        let num = RSeq::incr_num(Wrapping(i8::MAX)).map(|w| w.0);
+        assert_eq!(num.take(2), vec![i8::MAX, i8::MIN]);
+    }
+}
@@ -0,0 +1,184 @@
+use std::{future::Future, rc::Rc};
+
+use futures::{
+    future::{FutureExt, LocalBoxFuture, Shared},
+    stream::{self, Stream},
+};
+
+use crate::seq::RSeq;
+
+/// An infinite sequence whose elements may have to be awaited, such as the
+/// replies from a service that is polled for each one.
+///
+/// Like [`RSeq`], nothing is computed until it is first awaited, and each
+/// element is computed at most once; clones of a sequence share that work.
+pub struct AsyncRSeq<T> {
+    node: Shared<LocalBoxFuture<'static, (T, AsyncRSeq<T>)>>,
+}
+
+impl<T> Clone for AsyncRSeq<T> {
+    fn clone(&self) -> Self {
+        Self {
+            node: self.node.clone(),
+        }
+    }
+}
+
+impl<T> AsyncRSeq<T>
+where
+    T: Clone + 'static,
+{
+    /// Builds a sequence whose head and tail are produced by `fut` when the
+    /// sequence is first awaited.
+    fn lazy(fut: impl Future<Output = (T, Self)> + 'static) -> Self {
+        Self {
+            node: fut.boxed_local().shared(),
+        }
+    }
+
+    /// The sequence whose element `n` is the output of `f(n)`. Each future is
+    /// created and awaited when its element is first demanded.
+    pub fn from_async_fn<F, Fut>(f: F) -> Self
+    where
+        F: Fn(usize) -> Fut + 'static,
+        Fut: Future<Output = T> + 'static,
+    {
+        Self::from_async_fn_at(0, Rc::new(f))
+    }
+
+    fn from_async_fn_at<F, Fut>(n: usize, f: Rc<F>) -> Self
+    where
+        F: Fn(usize) -> Fut + 'static,
+        Fut: Future<Output = T> + 'static,
+    {
+        Self::lazy(async move { (f(n).await, Self::from_async_fn_at(n + 1, f)) })
+    }
+
+    /// Returns the first element of the sequence, computing it if needed.
+    pub async fn head(&self) -> T {
+        self.node.clone().await.0
+    }
+
+    /// Returns the sequence following the head. The tail's own elements are
+    /// not computed until they are awaited.
+    pub async fn tail(&self) -> Self {
+        self.node.clone().await.1
+    }
+
+    /// Collects the first `n` elements into a `Vec`.
+    pub async fn take(&self, n: usize) -> Vec<T> {
+        let mut out = Vec::with_capacity(n);
+        let mut curr = self.clone();
+        for _ in 0..n {
+            let (head, tail) = curr.node.await;
+            out.push(head);
+            curr = tail;
+        }
+        out
+    }
+
+    /// Applies `f` to every element.
+    pub fn map<U>(&self, f: impl Fn(T) -> U + 'static) -> AsyncRSeq<U>
+    where
+        U: Clone + 'static,
+    {
+        self.map_rc(Rc::new(f))
+    }
+
+    fn map_rc<U>(&self, f: Rc<dyn Fn(T) -> U>) -> AsyncRSeq<U>
+    where
+        U: Clone + 'static,
+    {
+        let node = self.node.clone();
+        AsyncRSeq::lazy(async move {
+            let (head, tail) = node.await;
+            (f(head), tail.map_rc(f))
+        })
+    }
+
+    /// Converts the sequence into a [`Stream`] of its elements, which never
+    /// ends.
+    pub fn into_stream(self) -> impl Stream<Item = T> {
+        stream::unfold(self, |seq| async move { Some(seq.node.await) })
+    }
+}
+
+impl<T> From<RSeq<T>> for AsyncRSeq<T>
+where
+    T: Clone + 'static,
+{
+    fn from(seq: RSeq<T>) -> Self {
+        Self::lazy(async move { (seq.head().clone(), seq.tail().into()) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::Cell,
+        future::Future,
+        pin::Pin,
+        rc::Rc,
+        task::{Context, Poll},
+    };
+
+    use futures::{executor::block_on, StreamExt};
+
+    use crate::{prelude::*, AsyncRSeq};
+
+    /// A future that is pending once before completing, standing in for I/O.
+    struct YieldOnce(bool);
+
+    impl Future for YieldOnce {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.0 {
+                Poll::Ready(())
+            } else {
+                self.0 = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    fn delayed_counter(calls: Rc<Cell<usize>>) -> AsyncRSeq<usize> {
+        AsyncRSeq::from_async_fn(move |n| {
+            let calls = Rc::clone(&calls);
+            async move {
+                YieldOnce(false).await;
+                calls.set(calls.get() + 1);
+                n
+            }
+        })
+    }
+
+    #[test]
+    fn take() {
+        let calls = Rc::new(Cell::new(0));
+        let s = delayed_counter(Rc::clone(&calls));
+        assert_eq!(calls.get(), 0);
+        assert_eq!(block_on(s.take(5)), vec![0, 1, 2, 3, 4]);
+        assert_eq!(block_on(s.take(5)), vec![0, 1, 2, 3, 4]);
+        assert_eq!(calls.get(), 5);
+        let tail = block_on(s.tail());
+        assert_eq!(block_on(tail.head()), 1);
+        assert_eq!(calls.get(), 5);
+    }
+
+    #[test]
+    fn map() {
+        let s = delayed_counter(Rc::new(Cell::new(0))).map(|n| n * 10);
+        assert_eq!(block_on(s.take(4)), vec![0, 10, 20, 30]);
+        let from_sync = AsyncRSeq::from(RSeq::incr(1)).map(|n| -n);
+        assert_eq!(block_on(from_sync.take(3)), vec![-1, -2, -3]);
+    }
+
+    #[test]
+    fn into_stream() {
+        let s = delayed_counter(Rc::new(Cell::new(0)));
+        let items: Vec<_> = block_on(s.into_stream().skip(2).take(3).collect());
+        assert_eq!(items, vec![2, 3, 4]);
+    }
+}
@@ -0,0 +1,295 @@
+use std::{
+    ops::{Add, Div, Mul, Sub},
+    sync::Arc,
+};
+
+use crate::{seq::RSeq, step::Step, thunk::SyncThunk};
+
+/// A thread-safe counterpart of [`RSeq`] that can be sent to and shared
+/// between threads.
+///
+/// It has the same lazy, memoized semantics as `RSeq`. In exchange for being
+/// `Send + Sync`, its elements must be `Send + Sync` and every closure given
+/// to its combinators must be `Send + Sync` too, so closures capturing `Rc`
+/// or `Cell` values are rejected.
+pub struct ARSeq<T> {
+    node: Arc<SyncThunk<Node<T>>>,
+    /// For sequences built by [`ARSeq::from_fn`], the index of the head and
+    /// the function every element is computed by.
+    #[cfg_attr(not(feature = "rayon"), allow(dead_code))]
+    pub(crate) index: Option<(usize, IndexFn<T>)>,
+}
+
+pub(crate) type IndexFn<T> = Arc<dyn Fn(usize) -> T + Send + Sync>;
+
+struct Node<T> {
+    head: T,
+    tail: ARSeq<T>,
+}
+
+impl<T> Clone for ARSeq<T> {
+    fn clone(&self) -> Self {
+        Self {
+            node: Arc::clone(&self.node),
+            index: self.index.clone(),
+        }
+    }
+}
+
+impl<T> Drop for ARSeq<T> {
+    fn drop(&mut self) {
+        // Unlink forced nodes one at a time, as for `RSeq`.
+        let mut next = Arc::get_mut(&mut self.node).and_then(SyncThunk::take);
+        while let Some(mut node) = next {
+            next = Arc::get_mut(&mut node.tail.node).and_then(SyncThunk::take);
+        }
+    }
+}
+
+impl<T> ARSeq<T> {
+    fn lazy(f: impl FnOnce() -> (T, Self) + Send + 'static) -> Self {
+        Self {
+            node: Arc::new(SyncThunk::new(move || {
+                let (head, tail) = f();
+                Node { head, tail }
+            })),
+            index: None,
+        }
+    }
+
+    /// Returns the first element of the sequence, computing it if needed.
+    pub fn head(&self) -> &T {
+        &self.node.force().head
+    }
+
+    /// Returns the sequence following the head.
+    pub fn tail(&self) -> Self {
+        self.node.force().tail.clone()
+    }
+}
+
+impl<T> ARSeq<T>
+where
+    T: Step + Clone + Send + Sync + 'static,
+{
+    /// The sequence `start, start + 1, start + 2, ...`, or in general `start`
+    /// followed by its successive [`Step::next`] values.
+    pub fn incr(start: T) -> Self {
+        Self::lazy(move || {
+            let next = start.next();
+            (start, Self::incr(next))
+        })
+    }
+}
+
+impl<T> ARSeq<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    /// The sequence that repeats `v` forever.
+    pub fn cnst(v: T) -> Self {
+        Self::lazy(move || (v.clone(), Self::cnst(v)))
+    }
+
+    /// The sequence whose element `n` is `f(n)`.
+    pub fn from_fn(f: impl Fn(usize) -> T + Send + Sync + 'static) -> Self {
+        Self::from_fn_at(0, Arc::new(f))
+    }
+
+    fn from_fn_at(n: usize, f: IndexFn<T>) -> Self {
+        let index = Some((n, Arc::clone(&f)));
+        let mut seq = Self::lazy(move || (f(n), Self::from_fn_at(n + 1, f)));
+        seq.index = index;
+        seq
+    }
+
+    /// Collects the first `n` elements into a `Vec`.
+    pub fn take(&self, n: usize) -> Vec<T> {
+        let mut out = Vec::with_capacity(n);
+        if n == 0 {
+            return out;
+        }
+        out.push(self.head().clone());
+        let mut curr = self.clone();
+        for _ in 1..n {
+            curr = curr.tail();
+            out.push(curr.head().clone());
+        }
+        out
+    }
+
+    /// Applies `f` to every element.
+    pub fn map<M>(&self, f: impl Fn(T) -> M + Send + Sync + 'static) -> ARSeq<M>
+    where
+        M: Clone + Send + Sync + 'static,
+    {
+        self.map_arc(Arc::new(f))
+    }
+
+    fn map_arc<M>(&self, f: Arc<dyn Fn(T) -> M + Send + Sync>) -> ARSeq<M>
+    where
+        M: Clone + Send + Sync + 'static,
+    {
+        let seq = self.clone();
+        ARSeq::lazy(move || (f(seq.head().clone()), seq.tail().map_arc(f)))
+    }
+
+    /// Keeps only the elements satisfying `f`.
+    ///
+    /// Loops forever if no further element satisfies `f`.
+    pub fn filter(&self, f: impl Fn(T) -> bool + Send + Sync + 'static) -> Self {
+        self.filter_arc(Arc::new(f))
+    }
+
+    fn filter_arc(&self, f: Arc<dyn Fn(T) -> bool + Send + Sync>) -> Self {
+        let seq = self.clone();
+        Self::lazy(move || {
+            let mut curr = seq;
+            loop {
+                let next = curr.tail();
+                if f(curr.head().clone()) {
+                    return (curr.head().clone(), next.filter_arc(f));
+                }
+                curr = next;
+            }
+        })
+    }
+
+    /// Combines corresponding elements of `self` and `other` with `f`.
+    pub fn zip_with<U, V>(
+        &self,
+        other: &ARSeq<U>,
+        f: impl Fn(T, U) -> V + Send + Sync + 'static,
+    ) -> ARSeq<V>
+    where
+        U: Clone + Send + Sync + 'static,
+        V: Clone + Send + Sync + 'static,
+    {
+        self.zip_with_arc(other, Arc::new(f))
+    }
+
+    fn zip_with_arc<U, V>(
+        &self,
+        other: &ARSeq<U>,
+        f: Arc<dyn Fn(T, U) -> V + Send + Sync>,
+    ) -> ARSeq<V>
+    where
+        U: Clone + Send + Sync + 'static,
+        V: Clone + Send + Sync + 'static,
+    {
+        let left = self.clone();
+        let right = other.clone();
+        ARSeq::lazy(move || {
+            (
+                f(left.head().clone(), right.head().clone()),
+                left.tail().zip_with_arc(&right.tail(), f),
+            )
+        })
+    }
+}
+
+impl<T> From<ARSeq<T>> for RSeq<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    fn from(seq: ARSeq<T>) -> Self {
+        RSeq::lazy(move || (seq.head().clone(), seq.tail().into()))
+    }
+}
+
+impl<T> Add for &ARSeq<T>
+where
+    T: Add<Output = T> + Clone + Send + Sync + 'static,
+{
+    type Output = ARSeq<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a + b)
+    }
+}
+
+impl<T> Mul for &ARSeq<T>
+where
+    T: Mul<Output = T> + Clone + Send + Sync + 'static,
+{
+    type Output = ARSeq<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a * b)
+    }
+}
+
+impl<T> Sub for &ARSeq<T>
+where
+    T: Sub<Output = T> + Clone + Send + Sync + 'static,
+{
+    type Output = ARSeq<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a - b)
+    }
+}
+
+impl<T> Div for &ARSeq<T>
+where
+    T: Div<Output = T> + Clone + Send + Sync + 'static,
+{
+    type Output = ARSeq<T>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a / b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use crate::prelude::*;
+
+    #[test]
+    fn combinators() {
+        let s = ARSeq::incr(0);
+        assert_eq!(s.map(|n| n * 2).take(4), vec![0, 2, 4, 6]);
+        assert_eq!(s.filter(|n| n % 3 == 0).take(3), vec![0, 3, 6]);
+        let t = ARSeq::cnst(2);
+        assert_eq!((&s + &t).take(3), vec![2, 3, 4]);
+        assert_eq!((&s - &t).take(3), vec![-2, -1, 0]);
+        assert_eq!((&s * &t).take(3), vec![0, 2, 4]);
+        assert_eq!((&s / &t).take(3), vec![0, 0, 1]);
+    }
+
+    #[test]
+    fn send_to_thread() {
+        let s = ARSeq::incr(0u64).map(|n| n * n);
+        let shared = s.clone();
+        let handle = thread::spawn(move || shared.take(5));
+        assert_eq!(handle.join().unwrap(), vec![0, 1, 4, 9, 16]);
+        assert_eq!(s.take(5), vec![0, 1, 4, 9, 16]);
+    }
+
+    #[test]
+    fn capturing_closures() {
+        let names = Vec::from(["zero", "one", "two"]);
+        let words = ARSeq::incr(0usize).map(move |n| names[n % 3].to_string());
+        let banned = String::from("one");
+        let kept = words.filter(move |w| *w != banned);
+        let suffix = String::from("!");
+        let shouted = kept.zip_with(&ARSeq::incr(0), move |w, n| format!("{w}{n}{suffix}"));
+        let handle = thread::spawn(move || shouted.take(3));
+        assert_eq!(handle.join().unwrap(), vec!["zero0!", "two1!", "zero2!"]);
+    }
+
+    #[test]
+    fn from_fn() {
+        let s = ARSeq::from_fn(|n| n * n);
+        assert_eq!(s.take(4), vec![0, 1, 4, 9]);
+        assert_eq!(s.tail().tail().take(2), vec![4, 9]);
+    }
+
+    #[test]
+    fn into_rseq() {
+        let s = RSeq::from(ARSeq::incr(3));
+        assert_eq!(s.take(3), vec![3, 4, 5]);
+    }
+}
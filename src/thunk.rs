@@ -0,0 +1,180 @@
+use alloc::boxed::Box;
+use core::cell::{Cell, OnceCell};
+#[cfg(all(feature = "std", panic = "unwind"))]
+use std::panic::{self, AssertUnwindSafe};
+#[cfg(feature = "std")]
+use std::sync::{Mutex, OnceLock};
+
+#[cfg(all(feature = "std", panic = "unwind"))]
+thread_local! {
+    /// How many more thunks this thread may compute, while limited by
+    /// [`with_fuel`].
+    static FUEL: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// Unwinding payload used when [`FUEL`] runs out.
+#[cfg(all(feature = "std", panic = "unwind"))]
+struct OutOfFuel;
+
+/// Runs `f`, returning `None` instead if it would compute more than `fuel`
+/// thunks on this thread. Nested calls share the enclosing budget.
+///
+/// Running out unwinds out of every thunk in the middle of its computation.
+/// While fuel is limited each thunk keeps a copy of its initializer until
+/// it finishes, and puts it back if it is unwound, so interrupted thunks can
+/// be forced again later. Without unwinding there would be no way to stop,
+/// so none of this exists under `panic = "abort"`.
+#[cfg(all(feature = "std", panic = "unwind"))]
+pub(crate) fn with_fuel<R>(fuel: usize, f: impl FnOnce() -> R) -> Option<R> {
+    let outer = FUEL.get();
+    let limit = outer.map_or(fuel, |left| left.min(fuel));
+    FUEL.set(Some(limit));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    let used = limit - FUEL.get().unwrap_or(0);
+    FUEL.set(outer.map(|left| left - used));
+    match result {
+        Ok(out) => Some(out),
+        Err(payload) if payload.is::<OutOfFuel>() => None,
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
+
+/// Uses up one unit of fuel, unwinding if there is none left. Returns
+/// whether fuel is being limited at all.
+#[cfg(all(feature = "std", panic = "unwind"))]
+fn burn_fuel() -> bool {
+    let Some(left) = FUEL.get() else {
+        return false;
+    };
+    if left == 0 {
+        panic::resume_unwind(Box::new(OutOfFuel));
+    }
+    FUEL.set(Some(left - 1));
+    true
+}
+
+/// A thunk's initializer, which can be copied so that it survives being
+/// interrupted.
+trait Init<V> {
+    fn call(self: Box<Self>) -> V;
+
+    #[cfg(all(feature = "std", panic = "unwind"))]
+    fn clone_box(&self) -> Box<dyn Init<V>>;
+}
+
+impl<V, F> Init<V> for F
+where
+    F: FnOnce() -> V + Clone + 'static,
+{
+    fn call(self: Box<Self>) -> V {
+        self()
+    }
+
+    #[cfg(all(feature = "std", panic = "unwind"))]
+    fn clone_box(&self) -> Box<dyn Init<V>> {
+        Box::new(self.clone())
+    }
+}
+
+/// Puts a copy of an initializer back into its slot unless disarmed, so
+/// that a thunk unwound by running out of fuel can be forced again.
+#[cfg(all(feature = "std", panic = "unwind"))]
+struct Restore<'a, V> {
+    slot: &'a Cell<Option<Box<dyn Init<V>>>>,
+    backup: Option<Box<dyn Init<V>>>,
+}
+
+#[cfg(all(feature = "std", panic = "unwind"))]
+impl<V> Drop for Restore<'_, V> {
+    fn drop(&mut self) {
+        if let Some(backup) = self.backup.take() {
+            self.slot.set(Some(backup));
+        }
+    }
+}
+
+/// A lazily computed value that is evaluated at most once.
+pub(crate) struct Thunk<V> {
+    value: OnceCell<V>,
+    init: Cell<Option<Box<dyn Init<V>>>>,
+}
+
+impl<V> Thunk<V> {
+    pub(crate) fn new(init: impl FnOnce() -> V + Clone + 'static) -> Self {
+        Self {
+            value: OnceCell::new(),
+            init: Cell::new(Some(Box::new(init))),
+        }
+    }
+
+    pub(crate) fn ready(value: V) -> Self {
+        Self {
+            value: OnceCell::from(value),
+            init: Cell::new(None),
+        }
+    }
+
+    /// Computes the value on first use and returns the cached value after.
+    pub(crate) fn force(&self) -> &V {
+        self.value.get_or_init(|| {
+            #[cfg(all(feature = "std", panic = "unwind"))]
+            let limited = burn_fuel();
+            let init = self
+                .init
+                .take()
+                .expect("sequence element forced while it was being computed");
+            #[cfg(all(feature = "std", panic = "unwind"))]
+            if limited {
+                let mut restore = Restore {
+                    slot: &self.init,
+                    backup: Some(init.clone_box()),
+                };
+                let value = init.call();
+                restore.backup = None;
+                return value;
+            }
+            init.call()
+        })
+    }
+
+    /// Removes the cached value, if it has been computed.
+    pub(crate) fn take(&mut self) -> Option<V> {
+        self.value.take()
+    }
+}
+
+/// A thread-safe [`Thunk`].
+#[cfg(feature = "std")]
+pub(crate) struct SyncThunk<V> {
+    value: OnceLock<V>,
+    init: Mutex<Option<Box<dyn FnOnce() -> V + Send>>>,
+}
+
+#[cfg(feature = "std")]
+impl<V> SyncThunk<V> {
+    pub(crate) fn new(init: impl FnOnce() -> V + Send + 'static) -> Self {
+        Self {
+            value: OnceLock::new(),
+            init: Mutex::new(Some(Box::new(init))),
+        }
+    }
+
+    /// Computes the value on first use and returns the cached value after.
+    /// Threads racing to force the same thunk wait for the first one.
+    pub(crate) fn force(&self) -> &V {
+        self.value.get_or_init(|| {
+            let init = self
+                .init
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .take()
+                .expect("sequence element forced while it was being computed");
+            init()
+        })
+    }
+
+    /// Removes the cached value, if it has been computed.
+    pub(crate) fn take(&mut self) -> Option<V> {
+        self.value.take()
+    }
+}
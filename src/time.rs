@@ -0,0 +1,107 @@
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Weekday};
+
+use crate::{error::Error, seq::RSeq};
+
+impl RSeq<NaiveDate> {
+    /// The dates `start, start + step, start + 2·step, ...`.
+    ///
+    /// Steps are added with chrono's arithmetic, so they cross month ends and
+    /// leap days as the calendar does.
+    ///
+    /// Returns [`Error::InvalidDateStep`] if `step` is zero or not a whole
+    /// number of days, since the dates would then never advance or advance
+    /// unevenly.
+    ///
+    /// # Panics
+    ///
+    /// Demanding an element panics if it falls outside chrono's date range.
+    pub fn dates(start: NaiveDate, step: Duration) -> Result<Self, Error> {
+        if step.is_zero() || step != Duration::days(step.num_days()) {
+            return Err(Error::InvalidDateStep);
+        }
+        Ok(Self::unfold(start, move |d| d + step))
+    }
+
+    /// The weekdays from `start` on, skipping Saturdays and Sundays.
+    pub fn business_days(start: NaiveDate) -> Self {
+        Self::unfold(start, |d| d + Duration::days(1))
+            .filter(|d| !matches!(d.weekday(), Weekday::Sat | Weekday::Sun))
+    }
+}
+
+impl RSeq<NaiveDateTime> {
+    /// The timestamps `start, start + step, start + 2·step, ...`.
+    ///
+    /// # Panics
+    ///
+    /// Demanding an element panics if it falls outside chrono's date range.
+    pub fn datetimes(start: NaiveDateTime, step: Duration) -> Self {
+        Self::unfold(start, move |t| t + step)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, NaiveDate};
+
+    use crate::{prelude::*, Error};
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn dates() {
+        let days = RSeq::dates(date(2023, 1, 30), Duration::days(1)).unwrap();
+        assert_eq!(
+            days.take(5),
+            vec![
+                date(2023, 1, 30),
+                date(2023, 1, 31),
+                date(2023, 2, 1),
+                date(2023, 2, 2),
+                date(2023, 2, 3)
+            ]
+        );
+        let weeks = RSeq::dates(date(2024, 2, 22), Duration::weeks(1)).unwrap();
+        assert_eq!(
+            weeks.take(3),
+            vec![date(2024, 2, 22), date(2024, 2, 29), date(2024, 3, 7)]
+        );
+        let back = RSeq::dates(date(2024, 3, 1), Duration::days(-1)).unwrap();
+        assert_eq!(back.nth(1), date(2024, 2, 29));
+    }
+
+    #[test]
+    fn dates_invalid_step() {
+        let start = date(2024, 1, 1);
+        for step in [
+            Duration::zero(),
+            Duration::hours(12),
+            Duration::hours(36),
+            Duration::days(1) + Duration::nanoseconds(1),
+            -Duration::minutes(1),
+        ] {
+            assert_eq!(RSeq::dates(start, step).err(), Some(Error::InvalidDateStep));
+        }
+    }
+
+    #[test]
+    fn datetimes() {
+        let start = date(2023, 12, 31).and_hms_opt(22, 0, 0).unwrap();
+        let hours = RSeq::datetimes(start, Duration::minutes(90));
+        assert_eq!(hours.nth(2), date(2024, 1, 1).and_hms_opt(1, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn business_days() {
+        // 2023-06-09 is a Friday.
+        let days = RSeq::business_days(date(2023, 6, 9));
+        assert_eq!(
+            days.take(3),
+            vec![date(2023, 6, 9), date(2023, 6, 12), date(2023, 6, 13)]
+        );
+        let from_saturday = RSeq::business_days(date(2023, 6, 10));
+        assert_eq!(*from_saturday.head(), date(2023, 6, 12));
+    }
+}
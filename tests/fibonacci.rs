@@ -0,0 +1,14 @@
+use rseq::prelude::*;
+
+#[test]
+fn fibonacci() {
+    let s = RSeq::unfold((0, 1), |(x, y)| (y, x + y)).map(|(x, _)| x);
+    assert_eq!(s.take(10), vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+}
+
+#[test]
+fn accessors() {
+    let s = RSeq::incr(0);
+    assert_eq!(*s.head(), 0);
+    assert_eq!(*s.tail().head(), 1);
+}